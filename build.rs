@@ -0,0 +1,11 @@
+// `macros::check_years!` and friends scan these at compile time but cargo can't
+// know that, so tell it to rebuild when a day or example gets added
+fn main() {
+    println!("cargo:rerun-if-changed=src/years");
+    for year in std::fs::read_dir("inputs").into_iter().flatten().flatten() {
+        println!(
+            "cargo:rerun-if-changed={}",
+            year.path().join("examples").display()
+        );
+    }
+}
@@ -1,42 +1,258 @@
 extern crate proc_macro;
+use std::path::Path;
+
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::parse_macro_input;
 
+#[proc_macro]
+/// Makes sure every file in `src/years` is a proper day and is declared.
+///
+/// The `pub mod` lines in `src/years.rs` and `src/years/yYYYY.rs` are the one list of
+/// days, `aoc scaffold` adds new ones. They're written out rather than generated so
+/// `cargo fmt` and rust-analyzer can find the files. [`get_day!`] and [`all_days!`]
+/// are built from those lines, this catches a day file that never got one.
+/// Anything off becomes a compile error naming it
+pub fn check_years(_: TokenStream) -> TokenStream {
+    let declared_years = declared_mods(Path::new("src/years.rs"));
+    let errors = scan_years().into_iter().flat_map(|(year, days)| {
+        let year_mod = format!("y{year}");
+        if !declared_years.contains(&year_mod) {
+            return vec![format!("add `pub mod {year_mod};` to `src/years.rs`")];
+        }
+        let year_file = format!("src/years/{year_mod}.rs");
+        let declared_days = declared_mods(Path::new(&year_file));
+        days.into_iter()
+            .filter_map(|day| match day {
+                Ok(num) => {
+                    let day_mod = format!("day{num:02}");
+                    (!declared_days.contains(&day_mod))
+                        .then(|| format!("add `pub mod {day_mod};` to `{year_file}`"))
+                }
+                Err(msg) => Some(msg),
+            })
+            .collect()
+    });
+    quote! { #(compile_error!(#errors);)* }.into()
+}
+
 #[proc_macro]
 /// I didn't want to have to add each new day to the match arm so this does it automatically
 /// Reading from a dir to generate the source is kinda hacky and probably the opposite of idiomatic
 /// but I kinda just wanted to write a macro so don't @ me
-pub fn get_day(day_num: TokenStream) -> TokenStream {
-    let day_num = parse_macro_input!(day_num as syn::Ident);
-    let dir_of_days = std::fs::read_dir("src/days").unwrap();
-    let day_nums: Vec<_> = dir_of_days
+///
+/// Takes a `(year, day_num)` tuple
+pub fn get_day(key: TokenStream) -> TokenStream {
+    let key = parse_macro_input!(key as syn::Expr);
+    let (years, day_nums): (Vec<_>, Vec<_>) = all_day_keys().into_iter().unzip();
+    let day_paths = day_paths(&years, &day_nums);
+    quote! {
+        match #key {
+            #((#years, #day_nums) => &#day_paths as &dyn Solver,)*
+            (year, day_num) => panic!("No Solution found for {year} day: {day_num}"),
+        }
+    }
+    .into()
+}
+
+#[proc_macro]
+/// Every declared day as `(year, day_num, &dyn Solver)` in order. Same trick as [`get_day!`]
+pub fn all_days(_: TokenStream) -> TokenStream {
+    let (years, day_nums): (Vec<_>, Vec<_>) = all_day_keys().into_iter().unzip();
+    let day_paths = day_paths(&years, &day_nums);
+    quote! {
+        [#((#years, #day_nums, &#day_paths as &dyn Solver)),*]
+    }
+    .into()
+}
+
+#[proc_macro]
+/// A `#[test]` for every star of every example in `inputs/<year>/examples`.
+///
+/// An example is `dayNN_<name>.txt` next to a `dayNN_<name>.toml` holding its answers
+/// in the same format as `answers/<year>/dayNN.toml`. Stars left out of the toml get no test
+pub fn example_tests(_: TokenStream) -> TokenStream {
+    let tests = scan_years().into_iter().flat_map(|(year, _)| {
+        let Ok(dir_of_examples) = std::fs::read_dir(format!("inputs/{year}/examples")) else {
+            return Vec::new();
+        };
+        let mut examples: Vec<_> = dir_of_examples
+            .filter_map(|dir_entry| {
+                let path = dir_entry.ok()?.path();
+                (path.extension()? == "txt").then_some(path)
+            })
+            .collect();
+        examples.sort();
+        examples
+            .iter()
+            .map(|path| example_test(year, path))
+            .collect()
+    });
+
+    quote! { #(#tests)* }.into()
+}
+
+fn example_test(year: u16, path: &Path) -> TokenStream2 {
+    let name = path.file_stem().unwrap().to_string_lossy().to_string();
+    let Some(day_num) = name.get(3..5).and_then(|n| n.parse::<usize>().ok()) else {
+        let msg = format!(
+            "example `{}` should be named `dayNN_<name>.txt`",
+            path.display()
+        );
+        return quote! { compile_error!(#msg); };
+    };
+    let answers_path = path.with_extension("toml");
+    let answers = match std::fs::read_to_string(&answers_path)
+        .map_err(|e| e.to_string())
+        .and_then(|s| s.parse::<toml::Table>().map_err(|e| e.to_string()))
+    {
+        Ok(answers) => answers,
+        Err(e) => {
+            let msg = format!(
+                "could not read answers for example `{name}` from `{}`: {e}",
+                answers_path.display()
+            );
+            return quote! { compile_error!(#msg); };
+        }
+    };
+
+    let day_path = &day_paths(&[year], &[day_num])[0];
+    let input_path = format!("/{}", path.display());
+    let stars = [("star1", false), ("star2", true)].into_iter().filter_map(|(star, is_second_star)| {
+        let expected = match answers.get(star)? {
+            toml::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        let test_name = format_ident!("y{year}_{name}_{star}");
+        Some(quote! {
+            #[test]
+            fn #test_name() {
+                let input = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), #input_path));
+                crate::verify::assert_example(#day_num, &#day_path, input, #is_second_star, #expected);
+            }
+        })
+    });
+    quote! { #(#stars)* }
+}
+
+/// Every `mod` declared in the file at `path`, nothing if it can't be read
+fn declared_mods(path: &Path) -> Vec<String> {
+    let Some(file) = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|source| syn::parse_file(&source).ok())
+    else {
+        return Vec::new();
+    };
+    file.items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Mod(m) => Some(m.ident.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// `(year, day_num)` of every day declared in `src/years.rs` and `src/years/yYYYY.rs`
+fn all_day_keys() -> Vec<(u16, usize)> {
+    let mut keys: Vec<_> = declared_mods(Path::new("src/years.rs"))
         .into_iter()
+        .filter_map(|year_mod| year_mod.strip_prefix('y')?.parse::<u16>().ok())
+        .flat_map(|year| {
+            declared_mods(Path::new(&format!("src/years/y{year}.rs")))
+                .into_iter()
+                .filter_map(|day_mod| day_mod.strip_prefix("day")?.parse::<usize>().ok())
+                .map(move |day_num| (year, day_num))
+        })
+        .collect();
+    keys.sort();
+    keys
+}
+
+/// Every `src/years/yYYYY` dir in order along with its days
+fn scan_years() -> Vec<(u16, Vec<Result<usize, String>>)> {
+    let dir_of_years = std::fs::read_dir("src/years").unwrap();
+    let mut years: Vec<_> = dir_of_years
         .filter_map(|dir_entry| {
-            let dir_entry = dir_entry.ok()?;
-            if dir_entry.file_type().ok()?.is_dir() {
+            let path = dir_entry.ok()?.path();
+            if !path.is_dir() {
                 return None;
             }
-
-            let file_name = dir_entry.file_name().to_string_lossy().to_string();
-            let day_num: usize = file_name[3..5].parse().ok()?;
-            Some(day_num)
+            let year = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix('y')?
+                .parse()
+                .ok()?;
+            Some((year, scan_days(&path)))
         })
         .collect();
-    let day_paths: Vec<TokenStream2> = day_nums
-        .iter()
-        .map(|day_num| {
-            format!("crate::days::day{:02}::Day{:02}", day_num, day_num)
-                .parse()
-                .unwrap()
+    years.sort_by_key(|(year, _)| *year);
+    years
+}
+
+/// Every file in a year's dir in order, or why it isn't a day
+fn scan_days(dir: &Path) -> Vec<Result<usize, String>> {
+    let dir_of_days = std::fs::read_dir(dir).unwrap();
+    let mut days: Vec<_> = dir_of_days
+        .into_iter()
+        .filter_map(|dir_entry| {
+            let path = dir_entry.ok()?.path();
+            if !path.is_file() || path.extension()? != "rs" {
+                return None;
+            }
+            Some((path.clone(), check_day(&path)))
         })
         .collect();
-    quote! {
-        match #day_num {
-            #(#day_nums => &#day_paths as &dyn Day,)*
-             _ => panic!("No Solution found for day: {}", #day_num),
-        }
+    days.sort_by(|(a, _), (b, _)| a.cmp(b));
+    days.into_iter().map(|(_, day)| day).collect()
+}
+
+/// A day lives in `dayNN.rs` and exports `pub struct DayNN`
+fn check_day(path: &Path) -> Result<usize, String> {
+    let file_name = path.display();
+    let day_num = path
+        .file_stem()
+        .and_then(|stem| stem.to_str()?.strip_prefix("day"))
+        .filter(|num| num.len() == 2)
+        .and_then(|num| num.parse::<usize>().ok())
+        .ok_or_else(|| format!("`{file_name}` should be named `dayNN.rs`"))?;
+
+    let source =
+        std::fs::read_to_string(path).map_err(|e| format!("could not read `{file_name}`: {e}"))?;
+    // rustc gives a much better error for broken syntax than we could
+    let Ok(file) = syn::parse_file(&source) else {
+        return Ok(day_num);
+    };
+    let day_struct = format!("Day{day_num:02}");
+    let exports_day = file.items.iter().any(|item| {
+        matches!(
+            item,
+            syn::Item::Struct(s) if s.ident == day_struct && matches!(s.vis, syn::Visibility::Public(_))
+        )
+    });
+    if !exports_day {
+        return Err(format!(
+            "`{file_name}` does not export `pub struct {day_struct}`"
+        ));
     }
-    .into()
+    Ok(day_num)
+}
+
+fn day_paths(years: &[u16], day_nums: &[usize]) -> Vec<TokenStream2> {
+    years
+        .iter()
+        .zip(day_nums)
+        .map(|(year, day_num)| {
+            format!(
+                "crate::years::y{year}::day{:02}::Day{:02}",
+                day_num, day_num
+            )
+            .parse()
+            .unwrap()
+        })
+        .collect()
 }
@@ -0,0 +1,63 @@
+use std::fmt::Display;
+
+use crate::collections::Grid;
+
+/// What a star produces.
+///
+/// Days pick whatever type is natural for their [`Day::Answer`](crate::days::Day::Answer)
+/// and it gets converted into one of these for printing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Str(String),
+    /// Some puzzles spell their answer out in a picture
+    Grid(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Self::Grid(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(i) => write!(f, "{i}"),
+            Self::Str(s) => write!(f, "{s}"),
+            Self::Grid(g) => write!(f, "{}", g.trim_end()),
+        }
+    }
+}
+
+macro_rules! from_ints {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(i: $int) -> Self {
+                    Self::Int(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_ints!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Self::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Self::Str(s.into())
+    }
+}
+
+impl<T: Display> From<Grid<T>> for Answer {
+    fn from(grid: Grid<T>) -> Self {
+        Self::Grid(grid.to_string())
+    }
+}
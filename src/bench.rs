@@ -0,0 +1,194 @@
+//! Benchmarking days in stress test mode
+//!
+//! Each step is timed on its own so a star's numbers never include parsing
+//! or allocating the input it runs on.
+
+use std::{
+    fmt::Display,
+    path::Path,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use colored::Colorize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+pub mod history;
+
+/// How to benchmark one step
+#[derive(Clone, Copy)]
+pub struct BenchConfig {
+    /// runs thrown away to warm up caches and the branch predictor
+    pub warm_up: u32,
+    pub runs: u32,
+}
+
+impl BenchConfig {
+    /// Time `f` on its own `runs` times after warming up
+    pub fn run<T>(&self, mut f: impl FnMut() -> T) -> Stats {
+        for _ in 0..self.warm_up {
+            std::hint::black_box(f());
+        }
+        let samples = (0..self.runs)
+            .map(|_| {
+                let start = Instant::now();
+                let out = f();
+                let elapsed = start.elapsed();
+                // dropping whatever the step built isn't part of the step
+                drop(std::hint::black_box(out));
+                elapsed
+            })
+            .collect();
+        Stats::new(samples)
+    }
+}
+
+/// Summary of a benchmarked step. Durations are in nanoseconds when written as json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+    #[serde(with = "nanos")]
+    pub max: Duration,
+    #[serde(with = "nanos")]
+    pub mean: Duration,
+    #[serde(with = "nanos")]
+    pub std_dev: Duration,
+    /// runs further than 1.5 IQR outside the middle half
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Can't benchmark without any runs");
+        samples.sort();
+
+        let nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        let q1 = percentile(&nanos, 25.);
+        let q3 = percentile(&nanos, 75.);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .filter(|n| **n < q1 - fence || **n > q3 + fence)
+            .count();
+
+        Self {
+            runs: samples.len(),
+            min: samples[0],
+            median: from_nanos(percentile(&nanos, 50.)),
+            p95: from_nanos(percentile(&nanos, 95.)),
+            max: samples[samples.len() - 1],
+            mean: from_nanos(mean),
+            std_dev: from_nanos(variance.sqrt()),
+            outliers,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?} | median {} | p95 {:?} | max {:?} | σ {:?}",
+            self.min,
+            format!("{:?}", self.median).bright_white(),
+            self.p95,
+            self.max,
+            self.std_dev
+        )?;
+        if self.outliers > 0 {
+            let outliers = format!("({} outliers)", self.outliers);
+            write!(f, " {}", outliers.yellow())?;
+        }
+        Ok(())
+    }
+}
+
+/// Everything we benchmarked for a day
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: usize,
+    pub parse: Stats,
+    /// None if the star failed so there was nothing to time
+    pub stars: [Option<Stats>; 2],
+}
+
+pub fn write_json(path: &Path, benches: &[DayBench]) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(benches)?;
+    std::fs::write(path, json)
+}
+
+/// linearly interpolated percentile of sorted samples
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = pct / 100. * (sorted.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+mod nanos {
+    use std::time::Duration;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub fn serialize<S: Serializer>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(d.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percentiles_interpolate() {
+        let sorted = [10., 20., 30., 40.];
+        assert_eq!(percentile(&sorted, 0.), 10.);
+        assert_eq!(percentile(&sorted, 50.), 25.);
+        assert_eq!(percentile(&sorted, 100.), 40.);
+    }
+
+    #[test]
+    fn stats_of_a_known_sample() {
+        // mean 5 and a standard deviation of exactly 2
+        let samples = [9, 4, 2, 5, 4, 7, 4, 5].map(Duration::from_micros).to_vec();
+        let stats = Stats::new(samples);
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.median, Duration::from_nanos(4500));
+        assert_eq!(stats.p95, Duration::from_nanos(8300));
+        assert_eq!(stats.max, Duration::from_micros(9));
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.std_dev, Duration::from_micros(2));
+        // the middle half is 4µs to 5.5µs so only 9µs is past the 1.5 IQR fence
+        assert_eq!(stats.outliers, 1);
+    }
+}
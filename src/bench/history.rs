@@ -0,0 +1,349 @@
+//! Every stress test run gets appended to `bench_history.jsonl` so we can tell
+//! whether tuning a day actually made it faster
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    fs::{
+        self,
+        OpenOptions,
+    },
+    io::{
+        ErrorKind,
+        Write,
+    },
+    path::Path,
+    process::Command,
+};
+
+use colored::Colorize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::{
+    DayBench,
+    Stats,
+};
+
+pub const HISTORY_FILE: &str = "bench_history.jsonl";
+
+/// One stress test run, stored as a single line of the history file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchRun {
+    pub timestamp: String,
+    /// `-dirty` on the end if there were uncommitted changes
+    pub commit: String,
+    /// set with `--save-baseline` so we can compare against it by name later
+    pub name: Option<String>,
+    pub days: Vec<DayBench>,
+}
+
+impl BenchRun {
+    pub fn new(name: Option<String>, days: Vec<DayBench>) -> Self {
+        Self {
+            timestamp: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            commit: current_commit(),
+            name,
+            days,
+        }
+    }
+
+    fn step(&self, (year, day): (u16, usize), step: Step) -> Option<&Stats> {
+        let day = self
+            .days
+            .iter()
+            .find(|bench| bench.year == year && bench.day == day)?;
+        match step {
+            Step::Parse => Some(&day.parse),
+            Step::Star(star) => day.stars[star - 1].as_ref(),
+        }
+    }
+}
+
+impl Display for BenchRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "`{name}` ")?;
+        }
+        write!(f, "at {} ({})", self.commit, self.timestamp)
+    }
+}
+
+/// Oldest run first. A missing history file is just an empty history
+pub fn load(path: &Path) -> Result<Vec<BenchRun>, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Could not read {}: {e}", path.display())),
+    };
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| format!("Invalid run on line {} of {}: {e}", i + 1, path.display()))
+        })
+        .collect()
+}
+
+pub fn append(path: &Path, run: &BenchRun) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", serde_json::to_string(run)?)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Parse,
+    Star(usize),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse => write!(f, "parse"),
+            Self::Star(star) => write!(f, "star {star}"),
+        }
+    }
+}
+
+/// How the latest run did against an older one, step by step
+pub struct Comparison<'a> {
+    current: &'a BenchRun,
+    /// the most recent matching run for each step, if there was one
+    rows: Vec<((u16, usize), Step, Option<&'a BenchRun>)>,
+    /// changes in median smaller than this percentage are noise
+    threshold: f64,
+}
+
+impl<'a> Comparison<'a> {
+    /// Compare `current` to the most recent run in `history` that has each step,
+    /// only looking at runs called `baseline` if given
+    pub fn new(
+        current: &'a BenchRun,
+        history: &'a [BenchRun],
+        baseline: Option<&str>,
+        threshold: f64,
+    ) -> Self {
+        let rows = current
+            .days
+            .iter()
+            .flat_map(|bench| {
+                let key = (bench.year, bench.day);
+                [Step::Parse, Step::Star(1), Step::Star(2)]
+                    .into_iter()
+                    .filter(move |step| current.step(key, *step).is_some())
+                    .map(move |step| (key, step))
+            })
+            .map(|(key, step)| {
+                let previous = history
+                    .iter()
+                    .rev()
+                    .filter(|run| baseline.is_none() || run.name.as_deref() == baseline)
+                    .find(|run| run.step(key, step).is_some());
+                (key, step, previous)
+            })
+            .collect();
+        Self {
+            current,
+            rows,
+            threshold,
+        }
+    }
+}
+
+impl Display for Comparison<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (key @ (year, day), step, previous) in &self.rows {
+            let name = format!("{year} day {day:>2} {step:<7}");
+            let current = self.current.step(*key, *step).unwrap();
+            let Some(previous) = previous else {
+                writeln!(
+                    f,
+                    "{name} {:>11?}  {}",
+                    current.median,
+                    "no baseline".yellow()
+                )?;
+                continue;
+            };
+            let before = previous.step(*key, *step).unwrap();
+            let Some((change, trend)) = change(before, current, self.threshold) else {
+                writeln!(
+                    f,
+                    "{name} {:>11?} -> {:>11?} {:>8} {}  {}",
+                    before.median,
+                    current.median,
+                    "n/a",
+                    "~".dimmed(),
+                    format!("vs {previous}").dimmed()
+                )?;
+                continue;
+            };
+            let verdict = match trend {
+                Ordering::Less => "faster".green(),
+                Ordering::Greater => "slower".red(),
+                Ordering::Equal => "~".dimmed(),
+            };
+            writeln!(
+                f,
+                "{name} {:>11?} -> {:>11?} {:>+7.1}% {verdict}  {}",
+                before.median,
+                current.median,
+                change,
+                format!("vs {previous}").dimmed()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Percentage change in median and which way it went, `Less` being faster.
+/// Anything under `threshold` percent is `Equal`. None if `before` was too quick
+/// to time, so there's nothing to take a percentage of
+fn change(before: &Stats, after: &Stats, threshold: f64) -> Option<(f64, Ordering)> {
+    let before = before.median.as_nanos() as f64;
+    if before == 0. {
+        return None;
+    }
+    let change = 100. * (after.median.as_nanos() as f64 - before) / before;
+    let trend = match change {
+        change if change <= -threshold => Ordering::Less,
+        change if change >= threshold => Ordering::Greater,
+        _ => Ordering::Equal,
+    };
+    Some((change, trend))
+}
+
+/// Short hash of HEAD. Unknown if we aren't in a git repo
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain"]) {
+        Some(status) if !status.is_empty() => format!("{commit}-dirty"),
+        _ => commit,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn stats(median_nanos: u64) -> Stats {
+        Stats::new(vec![Duration::from_nanos(median_nanos)])
+    }
+
+    /// Day 1 with the given star 1 timing, parse always takes 10ns
+    fn run(commit: &str, name: Option<&str>, star1: Option<u64>) -> BenchRun {
+        BenchRun {
+            timestamp: "2023-12-25 00:00:00".into(),
+            commit: commit.into(),
+            name: name.map(Into::into),
+            days: vec![DayBench {
+                year: 2023,
+                day: 1,
+                parse: stats(10),
+                stars: [star1.map(stats), None],
+            }],
+        }
+    }
+
+    /// Which commit each step of `current` got compared against
+    fn baselines<'a>(comparison: &Comparison<'a>) -> Vec<(Step, Option<&'a str>)> {
+        comparison
+            .rows
+            .iter()
+            .map(|(_, step, previous)| (*step, previous.map(|run| run.commit.as_str())))
+            .collect()
+    }
+
+    #[test]
+    fn picks_the_baseline() {
+        let history = [
+            run("named", Some("before"), Some(100)),
+            run("older", None, Some(100)),
+            run("newest", None, None),
+        ];
+        let current = run("current", None, Some(100));
+
+        // the newest run that has each step, named or not
+        let latest = Comparison::new(&current, &history, None, 5.);
+        assert_eq!(
+            baselines(&latest),
+            [
+                (Step::Parse, Some("newest")),
+                (Step::Star(1), Some("older"))
+            ]
+        );
+
+        let named = Comparison::new(&current, &history, Some("before"), 5.);
+        assert_eq!(
+            baselines(&named),
+            [(Step::Parse, Some("named")), (Step::Star(1), Some("named"))]
+        );
+
+        let missing = Comparison::new(&current, &history, Some("after"), 5.);
+        assert_eq!(
+            baselines(&missing),
+            [(Step::Parse, None), (Step::Star(1), None)]
+        );
+    }
+
+    #[test]
+    fn changes_under_the_threshold_are_noise() {
+        let trend = |before, after| change(&stats(before), &stats(after), 5.);
+        assert_eq!(trend(1000, 950), Some((-5., Ordering::Less)));
+        assert_eq!(trend(1000, 951), Some((-4.9, Ordering::Equal)));
+        assert_eq!(trend(1000, 1000), Some((0., Ordering::Equal)));
+        assert_eq!(trend(1000, 1049), Some((4.9, Ordering::Equal)));
+        assert_eq!(trend(1000, 1050), Some((5., Ordering::Greater)));
+    }
+
+    #[test]
+    fn zero_median_has_no_percentage() {
+        assert_eq!(change(&stats(0), &stats(100), 5.), None);
+
+        let history = [run("before", None, Some(0))];
+        let current = run("current", None, Some(100));
+        let printed = Comparison::new(&current, &history, None, 5.).to_string();
+        assert!(printed.contains("n/a"), "{printed}");
+        assert!(
+            !printed.contains("inf") && !printed.contains("NaN"),
+            "{printed}"
+        );
+    }
+
+    #[test]
+    fn history_round_trips() {
+        let path = std::env::temp_dir().join(format!("aoc_history_{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+        assert!(load(&path).unwrap().is_empty());
+
+        append(&path, &run("first", None, Some(100))).unwrap();
+        append(&path, &run("second", Some("before"), None)).unwrap();
+        let runs = load(&path).unwrap();
+        let commits = runs
+            .iter()
+            .map(|run| run.commit.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(commits, ["first", "second"]);
+        assert_eq!(runs[1].name.as_deref(), Some("before"));
+
+        fs::write(&path, "\n{\"broken\n").unwrap();
+        let err = load(&path).unwrap_err();
+        assert!(err.contains("line 2"), "{err}");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,9 +1,22 @@
 use std::{
-    convert::Infallible,
     default::Default,
     str::FromStr,
 };
 
+use crate::{
+    parse::{
+        Parse,
+        ParseError,
+        StrParseExt,
+    },
+    search,
+    utils::Direction,
+};
+
+mod view;
+
+pub use view::*;
+
 pub struct DigitSet {
     set: Vec<bool>,
 }
@@ -65,20 +78,56 @@ impl Default for DigitSet {
 
 pub type Index = (usize, usize);
 
+/// `(rows, cols)` to each neighbor going clockwise from north
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangle of `T`s, stored a row after the other in one buffer
 #[derive(Hash, Clone, PartialEq, Eq)]
 pub struct Grid<T> {
-    data: Vec<Vec<T>>,
+    data: Vec<T>,
+    width: usize,
+    height: usize,
 }
 
-impl<T: From<char>> FromStr for Grid<T> {
-    type Err = Infallible;
+impl<T> FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<ParseError>,
+{
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self {
-            data: s
-                .lines()
-                .map(|line| line.chars().map(T::from).collect())
-                .collect(),
-        })
+        let mut grid = Self::new();
+        for line in s.lines() {
+            let row = line.try_parse_chars().collect::<Result<Vec<_>, _>>()?;
+            let len = row.len();
+            if !grid.push_row(row) {
+                return Err(ParseError::at(
+                    line,
+                    format!("expected a row of {} but got {len}", grid.width),
+                ));
+            }
+        }
+        Ok(grid)
+    }
+}
+
+impl<'a, T> Parse<'a> for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Into<ParseError>,
+{
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        input.parse()
     }
 }
 
@@ -89,9 +138,19 @@ impl<T> std::ops::Index<Index> for Grid<T> {
     }
 }
 
+impl<T> std::ops::IndexMut<Index> for Grid<T> {
+    fn index_mut(&mut self, index: Index) -> &mut Self::Output {
+        self.get_mut(index).unwrap()
+    }
+}
+
 impl<T> std::default::Default for Grid<T> {
     fn default() -> Self {
-        Self { data: Vec::new() }
+        Self {
+            data: Vec::new(),
+            width: 0,
+            height: 0,
+        }
     }
 }
 
@@ -100,44 +159,224 @@ impl<T> Grid<T> {
         Self::default()
     }
 
+    /// where `i` lives in `data`, if it's in the grid at all
+    fn offset(&self, i: Index) -> Option<usize> {
+        (i.0 < self.height && i.1 < self.width).then(|| i.0 * self.width + i.1)
+    }
+
     pub fn get(&self, i: Index) -> Option<&T> {
-        self.data.get(i.0).and_then(|row| row.get(i.1))
+        self.offset(i).map(|offset| &self.data[offset])
     }
 
     pub fn get_mut(&mut self, i: Index) -> Option<&mut T> {
-        self.data.get_mut(i.0).and_then(|row| row.get_mut(i.1))
+        self.offset(i).map(|offset| &mut self.data[offset])
     }
 
     pub fn num_rows(&self) -> usize {
-        self.data.len()
+        self.height
     }
 
     pub fn num_cols(&self) -> usize {
-        self.data.get(0).map_or(0, |row| row.len())
+        self.width
     }
 
     pub fn cols(
         &self,
-    ) -> impl Iterator<Item = impl Iterator<Item = &T> + Clone + std::iter::DoubleEndedIterator>
-           + std::iter::DoubleEndedIterator
-           + Clone
+    ) -> impl std::iter::DoubleEndedIterator<
+        Item = impl std::iter::DoubleEndedIterator<Item = &T> + std::iter::ExactSizeIterator + Clone,
+    > + Clone
            + std::iter::ExactSizeIterator {
-        (0..self.data[0].len()).map(|i| self.data.iter().map(move |row| &row[i]))
+        // an empty grid can still be wide if it's had columns added
+        (0..self.width).map(|j| {
+            self.data
+                .get(j..)
+                .unwrap_or_default()
+                .iter()
+                .step_by(self.width)
+        })
     }
 
     pub fn set_col(&mut self, index: usize, new_vals: impl Iterator<Item = T>) {
+        assert!(index < self.width, "column {index} is outside the grid");
         self.data
             .iter_mut()
+            .skip(index)
+            .step_by(self.width)
             .zip(new_vals)
-            .for_each(|(mut_row, col_val)| mut_row[index] = col_val)
+            .for_each(|(square, col_val)| *square = col_val)
     }
 
-    pub fn rows(&self) -> std::slice::Iter<'_, Vec<T>> {
-        self.data.iter()
+    pub fn rows(&self) -> Rows<'_, T> {
+        Rows {
+            rest: &self.data,
+            width: self.width,
+            left: self.height,
+        }
     }
 
     pub fn set_row(&mut self, index: usize, new_vals: impl Iterator<Item = T>) {
-        self.data[index] = new_vals.collect()
+        let start = index * self.width;
+        self.data[start..start + self.width]
+            .iter_mut()
+            .zip(new_vals)
+            .for_each(|(square, row_val)| *square = row_val)
+    }
+
+    /// Every index in the grid, a row at a time
+    pub fn indices(&self) -> impl Iterator<Item = Index> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// The square one step `dir` from `i`, unless that's off the edge
+    pub fn step(&self, i: Index, dir: Direction) -> Option<Index> {
+        dir.try_go(i).filter(|next| self.offset(*next).is_some())
+    }
+
+    /// The squares north, east, south and west of `i` that are in the grid
+    pub fn neighbors4(&self, i: Index) -> impl Iterator<Item = Index> {
+        self.neighbors(i, &ORTHOGONAL, false)
+    }
+
+    /// [`Self::neighbors4`] and the diagonals
+    pub fn neighbors8(&self, i: Index) -> impl Iterator<Item = Index> {
+        self.neighbors(i, &ALL_AROUND, false)
+    }
+
+    /// Always four, going off one edge comes back on the opposite one.
+    /// Grids thinner than 3 will give the same square more than once
+    pub fn neighbors4_wrapping(&self, i: Index) -> impl Iterator<Item = Index> {
+        self.neighbors(i, &ORTHOGONAL, true)
+    }
+
+    /// [`Self::neighbors4_wrapping`] and the diagonals
+    pub fn neighbors8_wrapping(&self, i: Index) -> impl Iterator<Item = Index> {
+        self.neighbors(i, &ALL_AROUND, true)
+    }
+
+    fn neighbors(
+        &self,
+        i: Index,
+        diffs: &'static [(isize, isize)],
+        wrap: bool,
+    ) -> impl Iterator<Item = Index> {
+        let (height, width) = (self.height, self.width);
+        diffs.iter().filter_map(move |(di, dj)| {
+            if wrap {
+                let wrap = |x: usize, dx: isize, len: usize| {
+                    (x as isize + dx).rem_euclid(len as isize) as usize
+                };
+                return (height > 0 && width > 0)
+                    .then(|| (wrap(i.0, *di, height), wrap(i.1, *dj, width)));
+            }
+            let next = (i.0.checked_add_signed(*di)?, i.1.checked_add_signed(*dj)?);
+            (next.0 < height && next.1 < width).then_some(next)
+        })
+    }
+
+    /// Every square, a row at a time
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Every square reachable from `starts` going north, east, south and west
+    /// through squares `passable` lets in. Starts it doesn't let in stay unfilled.
+    /// A [`search::dfs`] that asks `passable` about every square up front
+    pub fn flood_fill(
+        &self,
+        starts: impl IntoIterator<Item = Index>,
+        passable: impl FnMut(Index, &T) -> bool,
+    ) -> Grid<bool> {
+        let open = self.map_indexed(passable);
+        let starts = starts.into_iter().filter(|i| open.get(*i) == Some(&true));
+        let search = search::dfs(
+            starts,
+            |i| self.neighbors4(*i).filter(|next| open[*next]),
+            |_, _| false,
+        );
+        let mut filled = self.map(|_| false);
+        for i in search.visited() {
+            filled[*i] = true;
+        }
+        filled
+    }
+
+    /// [`Self::flood_fill`] for when where you can go depends on how you got there.
+    ///
+    /// `moves` gets each square with the state it was entered in and gives where to
+    /// go next and in what state. Every square keeps the states it has been entered in,
+    /// which is what gets returned, so going round in circles stops. Looking them up
+    /// on the grid beats hashing them into a [`search::dfs`], there's only ever a few
+    pub fn walk<S: PartialEq, I: IntoIterator<Item = (Index, S)>>(
+        &self,
+        starts: impl IntoIterator<Item = (Index, S)>,
+        mut moves: impl FnMut(Index, &S, &T) -> I,
+    ) -> Grid<Vec<S>> {
+        let mut seen = self.map(|_| Vec::new());
+        let mut stack = starts.into_iter().collect::<Vec<_>>();
+        while let Some((i, state)) = stack.pop() {
+            let (Some(square), Some(states)) = (self.get(i), seen.get_mut(i)) else {
+                continue;
+            };
+            if states.contains(&state) {
+                continue;
+            }
+            stack.extend(moves(i, &state, square));
+            states.push(state);
+        }
+        seen
+    }
+
+    /// Same shape with `f` applied to every square
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// [`Self::map`] that also gets where each square is
+    pub fn map_indexed<U>(&self, mut f: impl FnMut(Index, &T) -> U) -> Grid<U> {
+        Grid {
+            data: self
+                .indices()
+                .zip(&self.data)
+                .map(|(i, square)| f(i, square))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Rows of any length, as long as they all match
+    pub fn try_from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, ParseError> {
+        let mut grid = Self::new();
+        for row in rows {
+            let len = row.len();
+            if !grid.push_row(row) {
+                return Err(ParseError::new(format!(
+                    "expected a row of {} but got {len}",
+                    grid.width
+                )));
+            }
+        }
+        Ok(grid)
+    }
+
+    /// The first row sets the width, the rest have to match it. `false` if it didn't
+    fn push_row(&mut self, row: impl IntoIterator<Item = T>) -> bool {
+        let start = self.data.len();
+        self.data.extend(row);
+        let len = self.data.len() - start;
+        if self.height == 0 {
+            self.width = len;
+        } else if len != self.width {
+            self.data.truncate(start);
+            return false;
+        }
+        self.height += 1;
+        true
     }
 }
 
@@ -154,14 +393,20 @@ impl<T: Default> Grid<T> {
     }
 
     pub fn add_cols(&mut self, cols: usize) {
-        self.into_iter()
-            .for_each(|row| row.extend((0..cols).map(|_| T::default())))
+        let width = self.width + cols;
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        self.data = Vec::with_capacity(width * self.height);
+        for _ in 0..self.height {
+            self.data.extend(old.by_ref().take(self.width));
+            self.data.extend((0..cols).map(|_| T::default()));
+        }
+        self.width = width;
     }
 
     pub fn add_rows(&mut self, rows: usize) {
-        let num_cols = self.num_cols();
         self.data
-            .extend((0..rows).map(|_| (0..num_cols).map(|_| T::default()).collect()))
+            .extend((0..rows * self.width).map(|_| T::default()));
+        self.height += rows;
     }
 }
 
@@ -169,37 +414,101 @@ impl<T> IntoIterator for Grid<T> {
     type Item = Vec<T>;
     type IntoIter = std::vec::IntoIter<Vec<T>>;
     fn into_iter(self) -> Self::IntoIter {
-        self.data.into_iter()
+        let mut data = self.data.into_iter();
+        (0..self.height)
+            .map(|_| data.by_ref().take(self.width).collect())
+            .collect::<Vec<_>>()
+            .into_iter()
     }
 }
 
 impl<'g, T> IntoIterator for &'g Grid<T> {
-    type Item = &'g Vec<T>;
-    type IntoIter = std::slice::Iter<'g, Vec<T>>;
+    type Item = &'g [T];
+    type IntoIter = Rows<'g, T>;
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter()
+        self.rows()
     }
 }
 
 impl<'g, T> IntoIterator for &'g mut Grid<T> {
-    type Item = &'g mut Vec<T>;
-    type IntoIter = std::slice::IterMut<'g, Vec<T>>;
+    type Item = &'g mut [T];
+    type IntoIter = RowsMut<'g, T>;
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter_mut()
+        RowsMut {
+            rest: &mut self.data,
+            width: self.width,
+            left: self.height,
+        }
     }
 }
 
-impl<T> From<Vec<Vec<T>>> for Grid<T> {
-    fn from(value: Vec<Vec<T>>) -> Self {
-        Self { data: value }
+/// A grid's rows a slice at a time. Goes by the height rather than how many
+/// squares there are so grids with no columns still have all their rows
+pub struct Rows<'g, T> {
+    rest: &'g [T],
+    width: usize,
+    left: usize,
+}
+
+// derive would want `T: Clone`
+impl<T> Clone for Rows<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
     }
 }
 
-impl<T> FromIterator<Vec<T>> for Grid<T> {
-    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
-        Self {
-            data: iter.into_iter().collect(),
-        }
+impl<'g, T> Iterator for Rows<'g, T> {
+    type Item = &'g [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        self.left = self.left.checked_sub(1)?;
+        let (row, rest) = self.rest.split_at(self.width);
+        self.rest = rest;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+impl<T> DoubleEndedIterator for Rows<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.left = self.left.checked_sub(1)?;
+        let (rest, row) = self.rest.split_at(self.rest.len() - self.width);
+        self.rest = rest;
+        Some(row)
+    }
+}
+
+impl<T> ExactSizeIterator for Rows<'_, T> {}
+
+/// [`Rows`] that can change the squares
+pub struct RowsMut<'g, T> {
+    rest: &'g mut [T],
+    width: usize,
+    left: usize,
+}
+
+impl<'g, T> Iterator for RowsMut<'g, T> {
+    type Item = &'g mut [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        self.left = self.left.checked_sub(1)?;
+        let (row, rest) = std::mem::take(&mut self.rest).split_at_mut(self.width);
+        self.rest = rest;
+        Some(row)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+impl<T> ExactSizeIterator for RowsMut<'_, T> {}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = ParseError;
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Self::try_from_rows(rows)
     }
 }
 
@@ -214,3 +523,84 @@ impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_stop_at_the_edges() {
+        let grid: Grid<char> = "abc\ndef\nghi".parse().unwrap();
+        let around =
+            |neighbors: Vec<Index>| neighbors.into_iter().map(|i| grid[i]).collect::<String>();
+
+        assert_eq!(around(grid.neighbors4((0, 0)).collect()), "bd");
+        assert_eq!(around(grid.neighbors8((0, 0)).collect()), "bed");
+        assert_eq!(around(grid.neighbors8((1, 1)).collect()), "bcfihgda");
+        assert_eq!(around(grid.neighbors4_wrapping((0, 0)).collect()), "gbdc");
+        assert_eq!(
+            around(grid.neighbors8_wrapping((2, 2)).collect()),
+            "fdgacbhe"
+        );
+
+        assert_eq!(grid.step((0, 1), Direction::North), None);
+        assert_eq!(grid.step((0, 2), Direction::East), None);
+        assert_eq!(grid.step((0, 2), Direction::South), Some((1, 2)));
+        assert_eq!(Direction::West.try_go((4, 0)), None);
+        assert_eq!(Direction::South.try_go((4, 0)), Some((5, 0)));
+    }
+
+    #[test]
+    fn rows_keep_the_shape() {
+        assert!(Grid::try_from(vec![vec![1, 2], vec![3]]).is_err());
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+
+        // blank lines are still rows, they just have nothing in them
+        let blank: Grid<char> = "\n\n".parse().unwrap();
+        assert_eq!(blank.num_rows(), 2);
+        assert_eq!(blank.rows().len(), 2);
+        assert!(blank.rows().rev().all(<[char]>::is_empty));
+        assert_eq!(blank.cols().len(), 0);
+
+        let mut grid = Grid::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert!(grid.rows().rev().eq([[3, 4], [1, 2]]));
+        for row in &mut grid {
+            row.reverse();
+        }
+        assert!(grid.rows().eq([[2, 1], [4, 3]]));
+    }
+
+    /// Big enough to blow the stack if either of these recursed
+    #[test]
+    fn fills_huge_grids() {
+        let size = 1000;
+        let open = Grid::try_from_rows(
+            (0..size).map(|i| (0..size).map(|j| i % 4 != 1 || j == size - 1).collect()),
+        )
+        .unwrap();
+
+        let filled = open.flood_fill([(0, 0)], |_, open| *open);
+        assert_eq!(
+            filled.iter().filter(|f| **f).count(),
+            open.iter().filter(|o| **o).count()
+        );
+        assert!(!filled[(1, 0)]);
+        assert!(open
+            .flood_fill([(1, 0)], |_, open| *open)
+            .iter()
+            .all(|f| !*f));
+
+        // snaking along every row of an empty grid, turning round at the ends
+        let empty = Grid::try_from(vec![vec![true; size]; size]).unwrap();
+        let walked = empty.walk([((0, 0), Direction::East)], |i, dir, _| {
+            match empty.step(i, *dir) {
+                Some(next) => Some((next, *dir)),
+                None => empty
+                    .step(i, Direction::South)
+                    .map(|next| (next, dir.invert())),
+            }
+        });
+        assert!(walked.iter().all(|states| states.len() == 1));
+        assert_eq!(walked[(size - 1, 0)], [Direction::West]);
+    }
+}
@@ -0,0 +1,320 @@
+//! Looking at a [`Grid`] transposed, rotated or flipped without copying it
+
+use std::fmt::Display;
+
+use super::{
+    Grid,
+    Index,
+};
+
+/// Reading squares out of either a [`Grid`] or a [`View`] of one, so the same
+/// code can run on every orientation
+pub trait ReadGrid<T> {
+    fn get(&self, i: Index) -> Option<&T>;
+    fn num_rows(&self) -> usize;
+    fn num_cols(&self) -> usize;
+
+    /// Row `i` left to right. Panics if there's no such row
+    fn row<'a>(
+        &'a self,
+        i: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone
+    where
+        T: 'a,
+    {
+        assert!(i < self.num_rows(), "row {i} is outside the grid");
+        (0..self.num_cols()).map(move |j| self.get((i, j)).unwrap())
+    }
+
+    /// Each row left to right, starting at the top
+    fn rows<'a>(
+        &'a self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone,
+    > + ExactSizeIterator
+           + Clone
+    where
+        T: 'a,
+    {
+        (0..self.num_rows()).map(move |i| self.row(i))
+    }
+
+    /// Each column top to bottom, starting on the left
+    #[allow(unused)] // until a day needs it
+    fn cols<'a>(
+        &'a self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone,
+    > + ExactSizeIterator
+           + Clone
+    where
+        T: 'a,
+    {
+        (0..self.num_cols())
+            .map(move |j| (0..self.num_rows()).map(move |i| self.get((i, j)).unwrap()))
+    }
+}
+
+impl<T> ReadGrid<T> for Grid<T> {
+    fn get(&self, i: Index) -> Option<&T> {
+        Grid::get(self, i)
+    }
+
+    fn num_rows(&self) -> usize {
+        Grid::num_rows(self)
+    }
+
+    fn num_cols(&self) -> usize {
+        Grid::num_cols(self)
+    }
+
+    fn row<'a>(
+        &'a self,
+        i: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone
+    where
+        T: 'a,
+    {
+        assert!(i < self.height, "row {i} is outside the grid");
+        self.data[i * self.width..(i + 1) * self.width].iter()
+    }
+
+    fn cols<'a>(
+        &'a self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone,
+    > + ExactSizeIterator
+           + Clone
+    where
+        T: 'a,
+    {
+        Grid::cols(self)
+    }
+}
+
+/// A [`Grid`] seen from another angle. Transforms stack, each one applies to
+/// the view as it already looks
+pub struct View<'g, T> {
+    grid: &'g Grid<T>,
+    transposed: bool,
+    /// upside down, after transposing
+    flipped_vertically: bool,
+    /// left to right, after transposing
+    flipped_horizontally: bool,
+}
+
+// derive would want `T: Clone`
+impl<T> Clone for View<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for View<'_, T> {}
+
+impl<T> Grid<T> {
+    /// The grid as it is, ready to be transformed
+    pub fn view(&self) -> View<'_, T> {
+        View {
+            grid: self,
+            transposed: false,
+            flipped_vertically: false,
+            flipped_horizontally: false,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Rows become columns
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        self.view().rotate_cw().to_grid()
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    /// Left to right
+    pub fn flip_horizontal(&self) -> Self {
+        self.view().flip_horizontal().to_grid()
+    }
+
+    /// Upside down
+    pub fn flip_vertical(&self) -> Self {
+        self.view().flip_vertical().to_grid()
+    }
+}
+
+impl<'g, T> View<'g, T> {
+    /// Rows become columns
+    pub fn transpose(self) -> Self {
+        Self {
+            transposed: !self.transposed,
+            flipped_vertically: self.flipped_horizontally,
+            flipped_horizontally: self.flipped_vertically,
+            ..self
+        }
+    }
+
+    pub fn rotate_cw(self) -> Self {
+        self.transpose().flip_horizontal()
+    }
+
+    pub fn rotate_ccw(self) -> Self {
+        self.transpose().flip_vertical()
+    }
+
+    /// Left to right
+    pub fn flip_horizontal(self) -> Self {
+        Self {
+            flipped_horizontally: !self.flipped_horizontally,
+            ..self
+        }
+    }
+
+    /// Upside down
+    pub fn flip_vertical(self) -> Self {
+        Self {
+            flipped_vertically: !self.flipped_vertically,
+            ..self
+        }
+    }
+
+    /// Where a square of the view is in the grid underneath, for writing back to it
+    pub fn source(&self, (i, j): Index) -> Index {
+        let i = match self.flipped_vertically {
+            true => self.num_rows() - 1 - i,
+            false => i,
+        };
+        let j = match self.flipped_horizontally {
+            true => self.num_cols() - 1 - j,
+            false => j,
+        };
+        match self.transposed {
+            true => (j, i),
+            false => (i, j),
+        }
+    }
+
+    /// Copies the view out into a grid of its own
+    pub fn to_grid(self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            data: self.rows().flatten().cloned().collect(),
+            width: self.num_cols(),
+            height: self.num_rows(),
+        }
+    }
+
+    /// Row `i` of the view read straight out of the grid's buffer, which is
+    /// much quicker than going through [`Self::get`] for every square
+    fn line(self, i: usize) -> impl DoubleEndedIterator<Item = &'g T> + ExactSizeIterator + Clone {
+        assert!(i < self.num_rows(), "row {i} is outside the grid");
+        (0..self.num_cols()).map(move |j| {
+            let (i, j) = self.source((i, j));
+            &self.grid.data[i * self.grid.width + j]
+        })
+    }
+}
+
+impl<T> ReadGrid<T> for View<'_, T> {
+    fn get(&self, i: Index) -> Option<&T> {
+        if i.0 >= self.num_rows() || i.1 >= self.num_cols() {
+            return None;
+        }
+        self.grid.get(self.source(i))
+    }
+
+    fn num_rows(&self) -> usize {
+        match self.transposed {
+            true => self.grid.num_cols(),
+            false => self.grid.num_rows(),
+        }
+    }
+
+    fn num_cols(&self) -> usize {
+        match self.transposed {
+            true => self.grid.num_rows(),
+            false => self.grid.num_cols(),
+        }
+    }
+
+    fn row<'a>(
+        &'a self,
+        i: usize,
+    ) -> impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone
+    where
+        T: 'a,
+    {
+        let view: View<'a, T> = *self;
+        view.line(i)
+    }
+
+    fn cols<'a>(
+        &'a self,
+    ) -> impl DoubleEndedIterator<
+        Item = impl DoubleEndedIterator<Item = &'a T> + ExactSizeIterator + Clone,
+    > + ExactSizeIterator
+           + Clone
+    where
+        T: 'a,
+    {
+        let view: View<'a, T> = self.transpose();
+        (0..view.num_rows()).map(move |j| view.line(j))
+    }
+}
+
+impl<T> std::ops::Index<Index> for View<'_, T> {
+    type Output = T;
+    fn index(&self, index: Index) -> &Self::Output {
+        self.get(index).unwrap()
+    }
+}
+
+impl<T: Display> Display for View<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for square in row {
+                write!(f, "{square}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn views_match_copies() {
+        let grid: Grid<char> = "abc\ndef".parse().unwrap();
+        let shown = |grid: Grid<char>| grid.to_string().trim_end().replace('\n', "/");
+
+        assert_eq!(shown(grid.transpose()), "ad/be/cf");
+        assert_eq!(shown(grid.rotate_cw()), "da/eb/fc");
+        assert_eq!(shown(grid.rotate_ccw()), "cf/be/ad");
+        assert_eq!(shown(grid.flip_horizontal()), "cba/fed");
+        assert_eq!(shown(grid.flip_vertical()), "def/abc");
+
+        let view = grid.view().rotate_cw().rotate_cw();
+        assert_eq!(view.to_string(), "fed\ncba\n");
+        assert!(view.rotate_cw().rotate_cw().to_grid() == grid);
+        assert!(view.transpose().rows().map(|row| row.count()).eq([2, 2, 2]));
+        let cols = view.cols().map(|col| col.collect::<String>());
+        assert!(cols.eq(["fc", "eb", "da"]));
+        assert_eq!(view.get((0, 3)), None);
+
+        // writing back through a view lands in the right place
+        let rotated = grid.view().rotate_ccw();
+        assert_eq!(rotated[(2, 1)], 'd');
+        assert_eq!(grid[rotated.source((2, 1))], 'd');
+    }
+}
@@ -1,31 +1,128 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
+use std::{
+    fmt::Display,
+    time::Duration,
+};
+
+use crate::{
+    answer::Answer,
+    parse::{
+        Parse,
+        ParseError,
+    },
+    visualize::Frames,
+};
 
 pub trait Day {
+    /// The puzzle input once parsed. Parsed once and shared by both stars
+    type Input<'a>: Parse<'a>;
+    /// Whatever the stars naturally produce. Converted to an [`Answer`] for printing
+    type Answer: Into<Answer>;
+
+    /// Which stars are written, neither unless the day says so. The runner
+    /// reports the others as [`SolveError::Unimplemented`] without running them.
+    /// Flip each one as its star gets written
+    const STARS: [bool; 2] = [false, false];
+
+    /// Override if the day needs more than the [`Parse`] impl of its input to get going
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+        Self::Input::parse(input)
+    }
+
     #[allow(unused)]
-    fn star1(&self, input: String) -> String {
-        "Unimplemented".into()
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Err(SolveError::Unimplemented)
     }
 
     #[allow(unused)]
-    fn star2(&self, input: String) -> String {
-        "Unimplemented".into()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Err(SolveError::Unimplemented)
+    }
+
+    /// Works a star out again drawing what it's doing into `frames`, for `--visualize`.
+    /// Days with nothing worth looking at leave this alone
+    #[allow(unused)]
+    fn visualize(
+        &self,
+        input: &Self::Input<'_>,
+        is_second_star: bool,
+        frames: &mut Frames,
+    ) -> Result<(), SolveError> {
+        Err(SolveError::Unimplemented)
+    }
+}
+
+/// [`Day`] with the associated types erased so the runner can pick one at runtime.
+/// `Sync` so stars can be run on their own thread, see [`crate::worker`]
+pub trait Solver: Sync {
+    fn parse<'a>(&'a self, input: &'a str) -> Result<Box<dyn Puzzle + 'a>, ParseError>;
+
+    fn implemented(&self, is_second_star: bool) -> bool;
+}
+
+/// A day's input after parsing, ready to run either star against
+pub trait Puzzle {
+    fn star1(&self) -> Result<Answer, SolveError>;
+    fn star2(&self) -> Result<Answer, SolveError>;
+    fn visualize(&self, is_second_star: bool, frames: &mut Frames) -> Result<(), SolveError>;
+}
+
+impl<D: Day + Sync> Solver for D {
+    fn parse<'a>(&'a self, input: &'a str) -> Result<Box<dyn Puzzle + 'a>, ParseError> {
+        let input = Day::parse(self, input)?;
+        Ok(Box::new(Parsed { day: self, input }))
+    }
+
+    fn implemented(&self, is_second_star: bool) -> bool {
+        D::STARS[is_second_star as usize]
     }
 }
+
+struct Parsed<'a, D: Day> {
+    day: &'a D,
+    input: D::Input<'a>,
+}
+
+impl<D: Day> Puzzle for Parsed<'_, D> {
+    fn star1(&self) -> Result<Answer, SolveError> {
+        self.day.star1(&self.input).map(Into::into)
+    }
+
+    fn star2(&self) -> Result<Answer, SolveError> {
+        self.day.star2(&self.input).map(Into::into)
+    }
+
+    fn visualize(&self, is_second_star: bool, frames: &mut Frames) -> Result<(), SolveError> {
+        self.day.visualize(&self.input, is_second_star, frames)
+    }
+}
+
+#[derive(Debug)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// Input parsed fine but the puzzle has no answer for it
+    NoAnswer(String),
+    Unimplemented,
+    /// Set by the runner, days never return these
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Parse(e) => write!(f, "Failed to parse input: {e}"),
+            Self::NoAnswer(why) => write!(f, "No answer: {why}"),
+            Self::Unimplemented => write!(f, "Unimplemented"),
+            Self::Panicked(msg) => write!(f, "Panicked: {msg}"),
+            Self::TimedOut(after) => write!(f, "Timed out after {after:?}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
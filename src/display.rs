@@ -1,14 +1,52 @@
 use colored::Colorize;
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    sync::atomic::{
+        AtomicBool,
+        Ordering::Relaxed,
+    },
+};
 
-pub fn banner() -> impl Display {
-    let mut x = "-".green().to_string();
-    for _ in 0..12 {
-        x.push_str("-".red().to_string().as_str());
-        x.push_str("-".green().to_string().as_str());
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+/// No colors, emoji or ascii art from here on. For `--plain` and for when
+/// stdout isn't a terminal
+pub fn set_plain() {
+    PLAIN.store(true, Relaxed);
+    colored::control::set_override(false);
+}
+
+pub fn is_plain() -> bool {
+    PLAIN.load(Relaxed)
+}
+
+/// `fancy` unless we're being plain
+pub fn fancy<'a>(fancy: &'a str, plain: &'a str) -> &'a str {
+    match is_plain() {
+        false => fancy,
+        true => plain,
     }
+}
+
+/// The hat with some room around it, or nothing at all when plain
+pub fn print_santa_hat() {
+    if !is_plain() {
+        println!("\n{}\n", santa_hat());
+    }
+}
+
+pub fn banner() -> impl Display {
+    candy_cane(25)
+}
 
-    x.to_string()
+/// line of dashes alternating green and red
+pub fn candy_cane(len: usize) -> String {
+    (0..len)
+        .map(|i| match i % 2 {
+            0 => "-".green().to_string(),
+            _ => "-".red().to_string(),
+        })
+        .collect()
 }
 
 ///               (#((##%%%%%((#            
@@ -1,10 +1,7 @@
 //! Adding Extension methods
 
 use std::{
-    fmt::{
-        Debug,
-        Display,
-    },
+    fmt::Debug,
     iter::Sum,
     marker::PhantomData,
     str::{
@@ -13,6 +10,8 @@ use std::{
     },
 };
 
+use crate::days::SolveError;
+
 pub trait StringExt {
     fn parsed_lines<T>(&self) -> ParsedIter<Lines<'_>, T>;
 }
@@ -58,13 +57,13 @@ where
     }
 }
 
-pub trait DisplayMethod {
-    fn display(self) -> Self;
+pub trait OptionExt<T> {
+    /// for when a missing value means the puzzle has no answer
+    fn or_no_answer(self, why: &str) -> Result<T, SolveError>;
 }
 
-impl<T: Display> DisplayMethod for T {
-    fn display(self) -> Self {
-        println!("{self}");
-        self
+impl<T> OptionExt<T> for Option<T> {
+    fn or_no_answer(self, why: &str) -> Result<T, SolveError> {
+        self.ok_or_else(|| SolveError::NoAnswer(why.into()))
     }
 }
@@ -0,0 +1,210 @@
+//! Finding the puzzle input for a day
+//!
+//! In order of preference:
+//! 1. `--input <path>`, or `--input -` to read stdin
+//! 2. `$AOC_INPUT_DIR/<year>/dayNN.txt`
+//! 3. `inputs/<year>/dayNN.txt` in the current directory
+//! 4. downloaded from adventofcode.com and saved to the first of those dirs,
+//!    if there is a session cookie in `$AOC_SESSION` or `~/.adventofcode.session`
+
+use std::{
+    env,
+    ffi::OsString,
+    fmt::Display,
+    fs,
+    io::{
+        self,
+        Read,
+    },
+    path::Path,
+};
+
+use crate::logging::log;
+
+pub mod provider;
+
+use provider::{
+    CachedProvider,
+    FileCache,
+    HttpFetcher,
+    InputProvider,
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Every place we looked for a day's input and why each one didn't work out.
+///
+/// Fits on one line normally, `{:#}` puts each place on its own line
+#[derive(Debug)]
+pub struct InputError {
+    year: u16,
+    day: usize,
+    tried: Vec<(String, io::Error)>,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Could not read the input for {} day {}",
+            self.year, self.day
+        )?;
+        if f.alternate() {
+            for (place, e) in &self.tried {
+                write!(f, "\n  tried {place}: {e}")?;
+            }
+            Ok(())
+        } else {
+            let places = self.tried.iter().map(|(place, _)| place.as_str());
+            write!(f, " from {}", itertools::join(places, " or "))
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// `explicit` is whatever was passed to `--input`, which wins over everything else
+pub fn read(year: u16, day_num: usize, explicit: Option<&Path>) -> Result<String, InputError> {
+    let fetcher = HttpFetcher::from_env();
+    let provider = CachedProvider {
+        caches: caches(env::var_os(INPUT_DIR_VAR), &env::current_dir().unwrap()),
+        fetcher: fetcher.map(|f| Box::new(f) as Box<dyn InputProvider>),
+    };
+    read_from(year, day_num, explicit, &mut io::stdin(), &provider)
+}
+
+/// `$AOC_INPUT_DIR` if it's set then `inputs` under `cwd`
+fn caches(input_dir: Option<OsString>, cwd: &Path) -> Vec<FileCache> {
+    let mut caches = Vec::new();
+    if let Some(dir) = input_dir {
+        caches.push(FileCache::new(dir));
+    }
+    caches.push(FileCache::new(cwd.join("inputs")));
+    caches
+}
+
+/// [`read`] with stdin and everywhere else it looks passed in
+fn read_from(
+    year: u16,
+    day_num: usize,
+    explicit: Option<&Path>,
+    stdin: &mut dyn Read,
+    provider: &CachedProvider,
+) -> Result<String, InputError> {
+    if let Some(path) = explicit {
+        if path == Path::new("-") {
+            log!("Reading stdin\n");
+            let mut input = String::new();
+            return match stdin.read_to_string(&mut input) {
+                Ok(_) => Ok(input),
+                Err(e) => Err(InputError {
+                    year,
+                    day: day_num,
+                    tried: vec![("stdin".to_string(), e)],
+                }),
+            };
+        }
+        return read_file(path).map_err(|e| InputError {
+            year,
+            day: day_num,
+            tried: vec![(path.display().to_string(), e)],
+        });
+    }
+
+    provider.get(year, day_num).map_err(|mut e| {
+        if provider.fetcher.is_none() {
+            let hint = format!("set ${} to download it", provider::SESSION_VAR);
+            e.tried
+                .push((HttpFetcher::AOC_URL.to_string(), io::Error::other(hint)));
+        }
+        e
+    })
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    log!("Reading {}\n", path.display());
+    fs::read_to_string(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    /// A fresh dir with `<year>/dayNN.txt` in it for each of `inputs`
+    fn input_dir(name: &str, inputs: &[(u16, usize, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (year, day, input) in inputs {
+            FileCache::new(&dir).store(*year, *day, input).unwrap();
+        }
+        dir
+    }
+
+    fn offline(caches: Vec<FileCache>) -> CachedProvider {
+        CachedProvider {
+            caches,
+            fetcher: None,
+        }
+    }
+
+    #[test]
+    fn looks_in_order() {
+        let env_dir = input_dir("env", &[(2023, 1, "from env")]);
+        let cwd = input_dir("cwd", &[]);
+        FileCache::new(cwd.join("inputs"))
+            .store(2023, 1, "from cwd")
+            .unwrap();
+        let file = env_dir.join("explicit.txt");
+        fs::write(&file, "from file").unwrap();
+
+        let provider = offline(caches(Some(env_dir.clone().into()), &cwd));
+        let read = |explicit: Option<&Path>| {
+            read_from(2023, 1, explicit, &mut "from stdin".as_bytes(), &provider).unwrap()
+        };
+        assert_eq!(read(Some(&file)), "from file");
+        assert_eq!(read(Some(Path::new("-"))), "from stdin");
+        assert_eq!(read(None), "from env");
+        fs::remove_file(env_dir.join("2023").join("day01.txt")).unwrap();
+        assert_eq!(read(None), "from cwd");
+
+        // without $AOC_INPUT_DIR it goes straight to the current dir
+        let provider = offline(caches(None, &cwd));
+        assert_eq!(provider.get(2023, 1).unwrap(), "from cwd");
+
+        fs::remove_dir_all(env_dir).unwrap();
+        fs::remove_dir_all(cwd).unwrap();
+    }
+
+    #[test]
+    fn lists_everywhere_it_looked() {
+        let env_dir = input_dir("nowhere-env", &[]);
+        let cwd = input_dir("nowhere-cwd", &[]);
+        let provider = offline(caches(Some(env_dir.clone().into()), &cwd));
+
+        let err = read_from(2023, 7, None, &mut io::empty(), &provider).unwrap_err();
+        let places = err
+            .tried
+            .iter()
+            .map(|(place, _)| place.as_str())
+            .collect::<Vec<_>>();
+        let day07 = |dir: PathBuf| dir.join("2023").join("day07.txt").display().to_string();
+        assert_eq!(
+            places,
+            [
+                day07(env_dir).as_str(),
+                day07(cwd.join("inputs")).as_str(),
+                HttpFetcher::AOC_URL
+            ]
+        );
+        let listed = format!("{err:#}");
+        assert_eq!(listed.lines().count(), 4, "{listed}");
+        assert!(listed.contains("set $AOC_SESSION"), "{listed}");
+
+        let missing = Path::new("no/such/input.txt");
+        let err = read_from(2023, 7, Some(missing), &mut io::empty(), &provider).unwrap_err();
+        assert_eq!(err.tried.len(), 1);
+        assert_eq!(err.tried[0].0, missing.display().to_string());
+    }
+}
@@ -0,0 +1,303 @@
+//! Where a day's input comes from when it isn't passed in with `--input`
+
+use std::{
+    env,
+    fs,
+    io,
+    path::PathBuf,
+};
+
+use super::InputError;
+use crate::logging::log;
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Same file aoc-cli reads its session from so we can share it
+const SESSION_FILE: &str = ".adventofcode.session";
+
+pub const USER_AGENT: &str = "github.com/tStreichenberger/aoc_23";
+
+/// The adventofcode.com session cookie from `$AOC_SESSION` or `~/.adventofcode.session`
+pub fn session() -> Option<String> {
+    let session = env::var(SESSION_VAR).ok().or_else(|| {
+        let home = env::var_os("HOME")?;
+        fs::read_to_string(PathBuf::from(home).join(SESSION_FILE)).ok()
+    })?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+pub trait InputProvider {
+    /// Where this looks for a day's input, for when it can't be found
+    fn describe(&self, year: u16, day: usize) -> String;
+
+    fn get(&self, year: u16, day: usize) -> io::Result<String>;
+}
+
+/// Inputs saved as `<dir>/<year>/dayNN.txt`
+pub struct FileCache {
+    dir: PathBuf,
+}
+
+impl FileCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, year: u16, day: usize) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn store(&self, year: u16, day: usize, input: &str) -> io::Result<()> {
+        let path = self.path(year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        fs::write(&path, input)?;
+        log!("Saved input to {}", path.display());
+        Ok(())
+    }
+}
+
+impl InputProvider for FileCache {
+    fn describe(&self, year: u16, day: usize) -> String {
+        self.path(year, day).display().to_string()
+    }
+
+    fn get(&self, year: u16, day: usize) -> io::Result<String> {
+        let path = self.path(year, day);
+        log!("Reading {}\n", path.display());
+        fs::read_to_string(path)
+    }
+}
+
+/// Downloads inputs from adventofcode.com, or anything that answers like it does
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+impl HttpFetcher {
+    pub const AOC_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+
+    /// Talks to adventofcode.com if we have a [`session`]
+    pub fn from_env() -> Option<Self> {
+        session().map(|session| Self::new(Self::AOC_URL, session))
+    }
+
+    fn url(&self, year: u16, day: usize) -> String {
+        format!("{}/{year}/day/{day}/input", self.base_url)
+    }
+}
+
+impl InputProvider for HttpFetcher {
+    fn describe(&self, year: u16, day: usize) -> String {
+        self.url(year, day)
+    }
+
+    fn get(&self, year: u16, day: usize) -> io::Result<String> {
+        let url = self.url(year, day);
+        log!("Downloading {url}\n");
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+        match response {
+            Ok(response) => response.into_string(),
+            Err(ureq::Error::Status(404, _)) => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "the puzzle isn't unlocked yet",
+            )),
+            Err(ureq::Error::Status(400 | 500, _)) => Err(io::Error::other(
+                "the session cookie was rejected, it has probably expired",
+            )),
+            Err(ureq::Error::Status(code, response)) => Err(io::Error::other(format!(
+                "got {code} {}",
+                response.status_text()
+            ))),
+            Err(e) => Err(io::Error::other(e)),
+        }
+    }
+}
+
+/// Looks through the caches in order then falls back to the fetcher,
+/// saving whatever it downloads into the first cache
+pub struct CachedProvider {
+    pub caches: Vec<FileCache>,
+    pub fetcher: Option<Box<dyn InputProvider>>,
+}
+
+impl CachedProvider {
+    pub fn get(&self, year: u16, day: usize) -> Result<String, InputError> {
+        let mut tried = Vec::new();
+        for cache in &self.caches {
+            match cache.get(year, day) {
+                Ok(input) => return Ok(input),
+                Err(e) => tried.push((cache.describe(year, day), e)),
+            }
+        }
+
+        if let Some(fetcher) = &self.fetcher {
+            match fetcher.get(year, day) {
+                Ok(input) => {
+                    if let Some(cache) = self.caches.first() {
+                        // no big deal, we'll just download it again next time
+                        if let Err(e) = cache.store(year, day, &input) {
+                            log!("Could not save input: {e}");
+                        }
+                    }
+                    return Ok(input);
+                }
+                Err(e) => tried.push((fetcher.describe(year, day), e)),
+            }
+        }
+
+        Err(InputError { year, day, tried })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{
+            BufRead,
+            BufReader,
+            Write,
+        },
+        net::TcpListener,
+        sync::{
+            Arc,
+            Mutex,
+        },
+        thread,
+    };
+
+    use super::*;
+
+    /// Stands in for adventofcode.com. Gives the same response to every request
+    /// and remembers the head of each one
+    struct FakeServer {
+        url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl FakeServer {
+        fn start(status: u16, body: &'static str) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let seen = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let request = BufReader::new(&stream)
+                        .lines()
+                        .map(Result::unwrap)
+                        .take_while(|line| !line.is_empty())
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    seen.lock().unwrap().push(request);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Fake\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+
+        fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetch_sends_session() {
+        let server = FakeServer::start(200, "1abc2\n");
+        let fetcher = HttpFetcher::new(&server.url, "hunter2");
+
+        assert_eq!(fetcher.get(2023, 1).unwrap(), "1abc2\n");
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2023/day/1/input "));
+        assert!(requests[0]
+            .to_lowercase()
+            .contains("cookie: session=hunter2"));
+    }
+
+    #[test]
+    fn fetch_locked_puzzle() {
+        let server = FakeServer::start(404, "Not Found");
+        let fetcher = HttpFetcher::new(&server.url, "hunter2");
+
+        let err = fetcher.get(2023, 25).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn downloads_once() {
+        let server = FakeServer::start(200, "0 3 6 9 12 15\n");
+        let dir = temp_dir("downloads-once");
+        let provider = CachedProvider {
+            caches: vec![FileCache::new(&dir)],
+            fetcher: Some(Box::new(HttpFetcher::new(&server.url, "hunter2"))),
+        };
+
+        assert_eq!(provider.get(2023, 9).unwrap(), "0 3 6 9 12 15\n");
+        assert_eq!(provider.get(2023, 9).unwrap(), "0 3 6 9 12 15\n");
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(
+            fs::read_to_string(dir.join("2023").join("day09.txt")).unwrap(),
+            "0 3 6 9 12 15\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn lists_everywhere_it_looked() {
+        let server = FakeServer::start(400, "Bad Request");
+        let provider = CachedProvider {
+            caches: vec![FileCache::new(temp_dir("everywhere"))],
+            fetcher: Some(Box::new(HttpFetcher::new(&server.url, "expired"))),
+        };
+
+        let err = provider.get(2023, 3).unwrap_err();
+        assert_eq!(err.tried.len(), 2);
+        assert!(err.tried[1].0.ends_with("/2023/day/3/input"));
+    }
+
+    #[test]
+    fn falls_through_the_caches() {
+        let dirs = ["first", "second", "third"].map(temp_dir);
+        FileCache::new(&dirs[1]).store(2023, 5, "second").unwrap();
+        FileCache::new(&dirs[2]).store(2023, 5, "third").unwrap();
+        FileCache::new(&dirs[2]).store(2023, 6, "third").unwrap();
+        let provider = CachedProvider {
+            caches: dirs.iter().map(FileCache::new).collect(),
+            fetcher: None,
+        };
+
+        // the first cache that has it wins, even if a later one has it too
+        assert_eq!(provider.get(2023, 5).unwrap(), "second");
+        assert_eq!(provider.get(2023, 6).unwrap(), "third");
+        let err = provider.get(2023, 7).unwrap_err();
+        assert_eq!(err.tried.len(), 3);
+        for dir in dirs.iter().filter(|dir| dir.exists()) {
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+}
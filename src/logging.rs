@@ -1,45 +1,182 @@
+//! `log!` and friends, printed Christmas style
+//!
+//! What gets logged is set with `$AOC_LOG`, same idea as `RUST_LOG`:
+//! a default level and/or `module=level` pairs separated by commas, like
+//! `warn,aoc::years::y2023::day17=trace`. `-v` and `-q` nudge the default level
+//! up and down from there.
+
 use colored::Colorize;
-use log::Log;
+use log::{
+    LevelFilter,
+    Log,
+};
 
-use std::sync::atomic::{
-    AtomicUsize,
-    Ordering::Relaxed,
+use std::{
+    fs::{
+        File,
+        OpenOptions,
+    },
+    io::Write,
+    path::Path,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering::Relaxed,
+        },
+        Mutex,
+    },
 };
 
+pub const LOG_VAR: &str = "AOC_LOG";
+
 const EMOJI: [&str; 10] = ["🎄", "🎁", "⭐️", "🦌", "⛄️", "🎄", "🎁", "⭐️", "🍪", "🥛"];
 
 static EMOJI_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-pub struct ChristmasLogger;
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LogStyle {
+    /// An emoji, the time and where it was logged from
+    #[default]
+    Christmas,
+    /// The level instead of an emoji and no colors
+    Plain,
+}
 
-impl ChristmasLogger {
-    pub fn init() {
-        log::set_logger(&Self).unwrap();
-        log::set_max_level(log::LevelFilter::Trace);
+/// Which modules log at what level
+#[derive(Debug, PartialEq)]
+pub struct Filters {
+    default: LevelFilter,
+    modules: Vec<(String, LevelFilter)>,
+}
+
+impl Filters {
+    /// `$AOC_LOG` style, see the module docs. Anything not mentioned logs at `default`
+    pub fn parse(spec: &str, default: LevelFilter) -> Result<Self, String> {
+        let mut filters = Self {
+            default,
+            modules: Vec::new(),
+        };
+        for directive in spec.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let parse_level = |level: &str| {
+                level
+                    .parse::<LevelFilter>()
+                    .map_err(|_| format!("`{level}` isn't a log level in `{directive}`"))
+            };
+            match directive.split_once('=') {
+                Some((module, level)) => filters
+                    .modules
+                    .push((module.trim().to_string(), parse_level(level.trim())?)),
+                None => filters.default = parse_level(directive)?,
+            }
+        }
+        Ok(filters)
+    }
+
+    /// Moves the default level `by` steps, more verbose if positive
+    pub fn shift(&mut self, by: i8) {
+        let levels = LevelFilter::iter().collect::<Vec<_>>();
+        let index = self.default as i8 + by;
+        self.default = levels[index.clamp(0, levels.len() as i8 - 1) as usize];
+    }
+
+    /// The most specific module filter that covers `target` wins
+    fn level(&self, target: &str) -> LevelFilter {
+        self.modules
+            .iter()
+            .filter(|(module, _)| {
+                target == module
+                    || target
+                        .strip_prefix(module.as_str())
+                        .is_some_and(|rest| rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn max(&self) -> LevelFilter {
+        self.modules
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
     }
 }
 
-impl Log for ChristmasLogger {
-    fn enabled(&self, _metadata: &log::Metadata) -> bool {
-        true
+pub struct ChristmasLogger {
+    filters: Filters,
+    style: LogStyle,
+    /// stderr if there isn't one
+    file: Option<Mutex<File>>,
+}
+
+impl ChristmasLogger {
+    pub fn init(filters: Filters, style: LogStyle, file: Option<&Path>) -> Result<(), String> {
+        let file = match file {
+            Some(path) => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|e| format!("Could not open {}: {e}", path.display()))?;
+                Some(Mutex::new(file))
+            }
+            None => None,
+        };
+        log::set_max_level(filters.max());
+        let logger = Self {
+            filters,
+            style,
+            file,
+        };
+        log::set_logger(Box::leak(Box::new(logger))).map_err(|e| e.to_string())
     }
 
-    fn log(&self, record: &log::Record) {
-        let filename = record.file().unwrap();
-        let line = record.line().unwrap();
-        let time_stamp = time_stamp().bright_red();
+    fn format(&self, record: &log::Record) -> String {
+        let filename = record.file().unwrap_or("?");
+        let line = record.line().unwrap_or_default();
+        let time_stamp = time_stamp();
         let args = record.args();
+        let location = format!("[{filename}:{line}]");
 
-        let location = format!("[{filename}:{line}]").green();
+        // colors don't belong in a file
+        if self.style == LogStyle::Plain || self.file.is_some() || crate::display::is_plain() {
+            return format!("{:<5} {time_stamp} {location} {args}", record.level());
+        }
 
         let emoji = {
             let emoji_index = EMOJI_COUNTER.fetch_add(1, Relaxed) % EMOJI.len();
             EMOJI[emoji_index]
         };
 
-        println!("{emoji} {time_stamp} {location} {args}")
+        format!(
+            "{emoji} {} {} {args}",
+            time_stamp.bright_red(),
+            location.green()
+        )
+    }
+}
+
+impl Log for ChristmasLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= self.filters.level(metadata.target())
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = self.format(record);
+        match &self.file {
+            // nowhere better to complain about it
+            Some(file) => drop(writeln!(file.lock().unwrap(), "{line}")),
+            None => eprintln!("{line}"),
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(file) = &self.file {
+            let _ = file.lock().unwrap().flush();
+        }
     }
-    fn flush(&self) {}
 }
 
 macro_rules! log {
@@ -52,7 +189,7 @@ pub(crate) use log;
 #[allow(unused)]
 macro_rules! debug {
     ($e:expr) => {{
-        crate::logging::log!("{} = {:#?}", std::stringify!($e), $e);
+        ::log::debug!("{} = {:#?}", std::stringify!($e), $e);
         $e
     }};
 }
@@ -65,3 +202,44 @@ fn time_stamp() -> String {
     let dt: chrono::DateTime<chrono::Utc> = now.into();
     format!("{}", dt.format("%H:%M:%S%.3f"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_module_wins() {
+        let filters = Filters::parse(
+            "warn, aoc::years=debug,aoc::years::y2023::day17=trace",
+            LevelFilter::Info,
+        )
+        .unwrap();
+        assert_eq!(filters.level("aoc::input::provider"), LevelFilter::Warn);
+        assert_eq!(
+            filters.level("aoc::years::y2023::day16"),
+            LevelFilter::Debug
+        );
+        assert_eq!(
+            filters.level("aoc::years::y2023::day17"),
+            LevelFilter::Trace
+        );
+        assert_eq!(
+            filters.level("aoc::years::y2023::day17x"),
+            LevelFilter::Debug
+        );
+        assert_eq!(filters.max(), LevelFilter::Trace);
+
+        assert!(Filters::parse("aoc=loud", LevelFilter::Info).is_err());
+    }
+
+    #[test]
+    fn shifting_stays_in_range() {
+        let mut filters = Filters::parse("", LevelFilter::Info).unwrap();
+        filters.shift(-2);
+        assert_eq!(filters.default, LevelFilter::Error);
+        filters.shift(-5);
+        assert_eq!(filters.default, LevelFilter::Off);
+        filters.shift(9);
+        assert_eq!(filters.default, LevelFilter::Trace);
+    }
+}
@@ -1,28 +1,94 @@
 use std::{
     env,
-    fs,
-    time::Instant,
+    io::{
+        self,
+        IsTerminal,
+    },
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
 };
 
-use clap::Parser;
+use clap::{
+    ArgAction,
+    Parser,
+    Subcommand,
+};
 use colored::Colorize;
 
+mod answer;
+mod bench;
 mod collections;
 mod days;
 mod display;
 mod ext;
+mod input;
 mod logging;
+mod parse;
+mod readme;
+mod report;
+mod scaffold;
+mod search;
+mod submit;
+mod summary;
 mod utils;
+mod verify;
+mod visualize;
+mod worker;
+mod years;
 
-use days::Day;
-use logging::log;
+use answer::Answer;
+use bench::{
+    history::{
+        self,
+        BenchRun,
+        Comparison,
+    },
+    BenchConfig,
+    DayBench,
+    Stats,
+};
+use days::{
+    Puzzle,
+    SolveError,
+    Solver,
+};
+use log::LevelFilter;
+use logging::{
+    log,
+    ChristmasLogger,
+    Filters,
+    LogStyle,
+};
+use submit::{
+    Outcome,
+    Submission,
+};
+use summary::{
+    DayRun,
+    DaySummary,
+    StarRun,
+    Summary,
+};
+use verify::{
+    Expected,
+    StarVerdict,
+    Tally,
+    Verdict,
+};
+use visualize::Frames;
+use worker::DayWorker;
 
 mod prelude {
     pub use crate::{
         collections::*,
-        days::Day,
+        days::{
+            Day,
+            SolveError,
+        },
         ext::*,
         logging::*,
+        parse::*,
         utils::*,
     };
     pub use itertools::Itertools;
@@ -34,12 +100,173 @@ mod prelude {
 }
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(short, long)]
     stress_test: bool,
-    #[arg(short = 'l', long, default_value_t = 1000)]
+    #[arg(short = 'l', long, default_value_t = 1000, value_parser = clap::value_parser!(u32).range(1..))]
     test_len: u32,
-    day_num: usize,
+    /// Runs to throw away before stress testing each step
+    #[arg(short, long, default_value_t = 10)]
+    warm_up: u32,
+    /// Also write the stress test results to this file as json
+    #[arg(long, requires = "stress_test")]
+    json: Option<PathBuf>,
+    /// Compare the stress test against the previous run, or the run saved with this name
+    #[arg(
+        long,
+        requires = "stress_test",
+        require_equals = true,
+        value_name = "BASELINE"
+    )]
+    compare: Option<Option<String>>,
+    /// Name this stress test run so it can be used with `--compare=<BASELINE>`
+    #[arg(long, requires = "stress_test", value_name = "BASELINE")]
+    save_baseline: Option<String>,
+    /// Percent change in median time before `--compare` calls it faster or slower
+    #[arg(long, default_value_t = 5.0)]
+    threshold: f64,
+    /// Check the stars against the accepted answers in `answers/` and fail on any mismatch
+    #[arg(long, conflicts_with = "stress_test")]
+    verify: bool,
+    /// Send the answers to Advent of Code, skipping any that are known to be wrong
+    #[arg(long, conflicts_with_all = ["stress_test", "verify"])]
+    submit: bool,
+    /// Pretend to submit, checking against the accepted answers in `answers/` instead
+    #[arg(long, requires = "submit")]
+    dry_run: bool,
+    /// Give up on parsing or a star after this many seconds. It keeps running in the background until we exit
+    #[arg(short, long, value_name = "SECS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+    /// Print the results for other programs instead, without colors or decorations
+    #[arg(long, value_enum, conflicts_with_all = ["stress_test", "verify", "submit"])]
+    format: Option<report::Format>,
+    /// Only print the answers, one per line. Errors go to stderr
+    #[arg(long, conflicts_with_all = ["stress_test", "verify", "submit", "format"])]
+    quiet: bool,
+    /// Watch the stars get worked out, on days that draw it. Only works with a single day
+    #[arg(long, conflicts_with_all = ["stress_test", "verify", "submit", "format", "quiet"])]
+    visualize: bool,
+    /// How many `--visualize` frames to show a second
+    #[arg(long, default_value_t = 20.0, value_parser = parse_fps)]
+    fps: f64,
+    /// Save the `--visualize` frames here to watch later with `aoc play`
+    #[arg(long, value_name = "PATH", requires = "visualize")]
+    frames_to: Option<PathBuf>,
+    /// No colors, emoji or ascii art. The default when stdout isn't a terminal
+    #[arg(long)]
+    plain: bool,
+    /// Log more, twice for everything. See `$AOC_LOG` for picking modules
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Log less, twice for only errors and three times for nothing. `--quiet` is the answers-only mode
+    #[arg(short = 'q', long, action = ArgAction::Count, global = true)]
+    quieter: u8,
+    /// Append the logs to this file instead of printing them to stderr
+    #[arg(long, value_name = "PATH", global = true)]
+    log_file: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t, global = true)]
+    log_style: LogStyle,
+    /// Which Advent of Code to run. Defaults to the latest one with solutions
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+    /// Read the input from here instead, `-` for stdin. Only works with a single day
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<PathBuf>,
+    /// A single day, `all`, or a range like `3..=10`
+    #[arg(required = true)]
+    days: Option<DaySelection>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Set up the files for a new day. `--year` defaults to this year
+    Scaffold {
+        /// Defaults to today
+        day: Option<usize>,
+        /// Open the puzzle in a browser too
+        #[arg(long)]
+        open: bool,
+    },
+    /// Rewrite the stars table and badges in README.md from the accepted answers
+    Readme,
+    /// Play back frames saved with `--frames-to`
+    Play {
+        path: PathBuf,
+        /// Frames a second
+        #[arg(long, default_value_t = 20.0, value_parser = parse_fps)]
+        fps: f64,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    Single(usize),
+    All,
+    /// inclusive on both ends
+    Range(usize, usize),
+}
+
+impl DaySelection {
+    fn contains(&self, day_num: usize) -> bool {
+        match *self {
+            Self::Single(day) => day == day_num,
+            Self::All => true,
+            Self::Range(start, end) => (start..=end).contains(&day_num),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.parse::<usize>()
+                .map_err(|_| format!("expected a day number but got `{day}`"))
+        };
+        if s == "all" {
+            return Ok(Self::All);
+        }
+        if let Some((start, end)) = s.split_once("..=") {
+            return Ok(Self::Range(parse_day(start)?, parse_day(end)?));
+        }
+        if let Some((start, end)) = s.split_once("..") {
+            let end = parse_day(end)?;
+            if end == 0 {
+                return Err(format!("`{s}` does not contain any days"));
+            }
+            return Ok(Self::Range(parse_day(start)?, end - 1));
+        }
+        parse_day(s).map(Self::Single)
+    }
+}
+
+impl std::fmt::Display for DaySelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Single(day) => write!(f, "day {day}"),
+            Self::All => write!(f, "any day"),
+            Self::Range(start, end) => write!(f, "days {start}..={end}"),
+        }
+    }
+}
+
+fn parse_timeout(secs: &str) -> Result<Duration, String> {
+    let secs = secs
+        .parse::<f64>()
+        .map_err(|_| format!("expected a number of seconds but got `{secs}`"))?;
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
+fn parse_fps(fps: &str) -> Result<f64, String> {
+    match fps.parse::<f64>() {
+        Ok(fps) if fps > 0. && fps.is_finite() => Ok(fps),
+        _ => Err(format!(
+            "expected a positive number of frames but got `{fps}`"
+        )),
+    }
 }
 
 lazy_static::lazy_static! {
@@ -47,47 +274,453 @@ lazy_static::lazy_static! {
 }
 
 fn main() {
-    logging::ChristmasLogger::init();
     set_panic_handler();
-    let day_num = ARGS.day_num;
 
-    let input = get_input(day_num);
+    if ARGS.plain || ARGS.quiet || ARGS.format.is_some() || !io::stdout().is_terminal() {
+        display::set_plain();
+    }
+    if env::var_os("NO_COLOR").is_some_and(|no_color| !no_color.is_empty()) {
+        colored::control::set_override(false);
+    }
+    init_logging();
+
+    if let Some(command) = &ARGS.command {
+        return run_command(command);
+    }
+    let selection = ARGS.days.expect("days are required without a command");
+
+    let year = ARGS.year.unwrap_or_else(latest_year);
+
+    for (flag, used) in [
+        ("--input", ARGS.input.is_some()),
+        ("--submit", ARGS.submit),
+        ("--visualize", ARGS.visualize),
+    ] {
+        if used && !matches!(selection, DaySelection::Single(_)) {
+            println!(
+                "{} `{flag}` only works with a single day, not {selection}",
+                "Error:".red(),
+            );
+            std::process::exit(1);
+        }
+    }
+
+    if ARGS.verify {
+        return run_verify(year, selection);
+    }
+
+    match selection {
+        DaySelection::Single(day_num) => run_single(year, day_num),
+        selection => run_many(year, selection),
+    }
+}
 
-    let day = macros::get_day!(day_num);
+/// `$AOC_LOG` first then `-v` and `-q` on top of it
+fn init_logging() {
+    let default = match ARGS.quiet {
+        true => LevelFilter::Warn,
+        false => LevelFilter::Info,
+    };
+    let spec = env::var(logging::LOG_VAR).unwrap_or_default();
+    let mut filters = Filters::parse(&spec, default).unwrap_or_else(|e| {
+        eprintln!("{} ${}: {e}", "Error:".red(), logging::LOG_VAR);
+        std::process::exit(1);
+    });
+    filters.shift(ARGS.verbose as i8 - ARGS.quieter as i8);
+    if let Err(e) = ChristmasLogger::init(filters, ARGS.log_style, ARGS.log_file.as_deref()) {
+        eprintln!("{} {e}", "Error:".red());
+        std::process::exit(1);
+    }
+}
 
-    println!("\n{}\n", display::santa_hat());
+fn latest_year() -> u16 {
+    macros::all_days!()
+        .into_iter()
+        .map(|(year, ..)| year)
+        .max()
+        .unwrap_or_else(scaffold::this_year)
+}
+
+/// The selected days of `year` in order
+fn select_days(year: u16, selection: DaySelection) -> Vec<(usize, &'static dyn Solver)> {
+    macros::all_days!()
+        .into_iter()
+        .filter(|(day_year, day_num, _)| *day_year == year && selection.contains(*day_num))
+        .map(|(_, day_num, day)| (day_num, day))
+        .collect()
+}
 
-    let to_run = match ARGS.stress_test {
-        false => run_star,
+fn run_single(year: u16, day_num: usize) {
+    let day = macros::get_day!((year, day_num));
+
+    let input = match get_input(year, day_num) {
+        Ok(input) => input,
+        Err(e) => {
+            println!("{} {e:#}", "Error:".red());
+            std::process::exit(1);
+        }
+    };
+
+    if ARGS.visualize {
+        return visualize(day_num, day, input);
+    }
+
+    if ARGS.quiet || ARGS.format.is_some() {
+        let run = solve_day(day, input).map_err(|e| e.to_string());
+        let summaries = [DaySummary { day: day_num, run }];
+        match ARGS.format {
+            Some(format) => print_report(format, year, &summaries),
+            None => print_answers(&summaries),
+        }
+        return;
+    }
+
+    display::print_santa_hat();
+
+    match ARGS.stress_test {
+        false => {
+            let answers = run_day(day, input);
+            if ARGS.submit {
+                submit(year, day_num, answers);
+            }
+        }
         true => {
             log!("Stress Testing over {} runs", ARGS.test_len);
-            stress_test_star
+            let bench = stress_test_day(year, day_num, day, input);
+            finish_stress_test(bench.into_iter().collect());
         }
+    }
+}
+
+fn visualize(day_num: usize, day: &'static dyn Solver, input: &'static str) {
+    let puzzle = day.parse(input).unwrap_or_else(|e| {
+        println!("{} {e}", "Error:".red());
+        std::process::exit(1);
+    });
+    let frames = match &ARGS.frames_to {
+        Some(path) => Frames::file(path),
+        None => Ok(Frames::terminal(ARGS.fps)),
+    };
+    let mut frames = frames.unwrap_or_else(|e| {
+        println!("{} Could not save frames: {e}", "Error:".red());
+        std::process::exit(1);
+    });
+
+    for is_second_star in [false, true] {
+        let star = is_second_star as usize + 1;
+        if !day.implemented(is_second_star) {
+            continue;
+        }
+        match puzzle.visualize(is_second_star, &mut frames) {
+            Ok(()) => (),
+            Err(SolveError::Unimplemented) => {
+                println!(
+                    "{}",
+                    format!("Day {day_num} star {star} has nothing to show").dimmed()
+                )
+            }
+            Err(e) => println!("{} Star {star}: {e}", "Error:".red()),
+        }
+    }
+
+    let count = frames.count();
+    if let Err(e) = frames.finish() {
+        println!("{} Could not save frames: {e}", "Error:".red());
+        std::process::exit(1);
+    }
+    if let Some(path) = &ARGS.frames_to {
+        println!("Saved {count} frames to {}", path.display());
+    }
+}
+
+fn submit(year: u16, day_num: usize, answers: [Option<Answer>; 2]) {
+    let submission = match ARGS.dry_run {
+        false => Submission::new(year, day_num),
+        true => Submission::dry_run(year, day_num),
     };
+    let mut submission = submission.unwrap_or_else(|e| {
+        println!("{} {e}", "Error:".red());
+        std::process::exit(1);
+    });
+    println!("{}", display::banner());
+    let sent = submission.submit_day(year, day_num, answers);
+    if sent.iter().any(|(_, outcome)| *outcome != Outcome::Correct) {
+        std::process::exit(1);
+    }
+}
+
+fn run_command(command: &Command) {
+    match command {
+        Command::Scaffold { day, open } => {
+            let year = ARGS.year.unwrap_or_else(scaffold::this_year);
+            let day_num = day.unwrap_or_else(scaffold::today);
+            if let Err(e) = scaffold::scaffold(year, day_num, *open) {
+                println!("{} {e}", "Error:".red());
+                std::process::exit(1);
+            }
+        }
+        Command::Readme => {
+            let year = ARGS.year.unwrap_or_else(latest_year);
+            if let Err(e) = update_readme(year) {
+                println!("{} {e}", "Error:".red());
+                std::process::exit(1);
+            }
+        }
+        Command::Play { path, fps } => {
+            if let Err(e) = visualize::play(path, *fps) {
+                println!("{} Could not play {}: {e}", "Error:".red(), path.display());
+                std::process::exit(1);
+            }
+        }
+    }
+}
 
-    to_run(day, input.clone(), false);
-    to_run(day, input, true)
+fn update_readme(year: u16) -> Result<(), String> {
+    let stars = readme::Stars::collect(year)?;
+    let path = readme::README;
+    let before =
+        std::fs::read_to_string(path).map_err(|e| format!("Could not read {path}: {e}"))?;
+    let after = stars.update(&before)?;
+    print!("{stars}");
+    if before == after {
+        println!("\n{path} is already up to date");
+        return Ok(());
+    }
+    std::fs::write(path, after).map_err(|e| format!("Could not write {path}: {e}"))?;
+    println!(
+        "\n{} {path} with {} stars",
+        "Updated:".green(),
+        stars.total()
+    );
+    Ok(())
 }
 
 fn set_panic_handler() {
     let default_hook = std::panic::take_hook();
 
     std::panic::set_hook(Box::new(move |panic_info| {
-        println!("{}", display::oops_santa());
+        // next to the panic message, stdout is for answers and `--format` reports
+        if !display::is_plain() {
+            eprintln!("{}", display::oops_santa());
+        }
         default_hook(panic_info)
     }))
 }
 
-fn get_input(day_num: usize) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filename = cwd.join("inputs").join(format!("day{:02}.txt", day_num));
-    log!("Reading {}\n", filename.display());
-    fs::read_to_string(filename).expect("Error while reading")
+/// Run every selected day and print a table of how it went
+fn run_many(year: u16, selection: DaySelection) {
+    let days = select_days(year, selection);
+
+    display::print_santa_hat();
+
+    if days.is_empty() {
+        println!(
+            "{} No solutions found for {selection} of {year}",
+            "Error:".red()
+        );
+        return;
+    }
+
+    if ARGS.stress_test {
+        log!("Stress Testing over {} runs", ARGS.test_len);
+    }
+
+    let mut summaries = Vec::new();
+    let mut benches = Vec::new();
+    for (day_num, day) in days {
+        let input = match get_input(year, day_num) {
+            Ok(input) => input,
+            Err(e) => {
+                summaries.push(DaySummary {
+                    day: day_num,
+                    run: Err(e.to_string()),
+                });
+                continue;
+            }
+        };
+
+        if ARGS.stress_test {
+            log!("Day {day_num}");
+            benches.extend(stress_test_day(year, day_num, day, input));
+            continue;
+        }
+
+        log!("Running day {day_num}");
+        let run = solve_day(day, input).map_err(|e| e.to_string());
+        summaries.push(DaySummary { day: day_num, run });
+    }
+
+    if let Some(format) = ARGS.format {
+        print_report(format, year, &summaries);
+    } else if ARGS.quiet {
+        print_answers(&summaries);
+    } else if !summaries.is_empty() {
+        println!("\n{}", Summary(summaries));
+    }
+    if ARGS.stress_test {
+        finish_stress_test(benches);
+    }
+}
+
+/// `--quiet` output. Exits non-zero if any star that's been written didn't come up with an answer
+fn print_answers(summaries: &[DaySummary]) {
+    let mut failed = false;
+    for summary in summaries {
+        let run = match &summary.run {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("Error: day {}: {e}", summary.day);
+                failed = true;
+                continue;
+            }
+        };
+        for (star, star_run) in (1..).zip(&run.stars) {
+            match &star_run.result {
+                Ok(answer) => println!("{answer}"),
+                Err(SolveError::Unimplemented) => {}
+                Err(e) => {
+                    eprintln!("Error: day {} star {star}: {e}", summary.day);
+                    failed = true;
+                }
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+fn print_report(format: report::Format, year: u16, summaries: &[DaySummary]) {
+    let records = report::records(year, summaries);
+    print!(
+        "{}",
+        report::render(format, &report::Environment::current(), &records)
+    );
+}
+
+/// Run every selected star that has an accepted answer and exit non-zero if any of them changed
+fn run_verify(year: u16, selection: DaySelection) {
+    let days = select_days(year, selection);
+
+    display::print_santa_hat();
+
+    if days.is_empty() {
+        println!(
+            "{} No solutions found for {selection} of {year}",
+            "Error:".red()
+        );
+        std::process::exit(1);
+    }
+
+    let mut tally = Tally::default();
+    for (day_num, day) in days {
+        // the whole day is broken so there is nothing to run the stars on
+        let mut reject = |why: String| {
+            let cross = display::fancy("✘", "FAIL");
+            println!("{} Day {day_num:>2} {}", cross.red(), why.red());
+            tally.failed += 1;
+        };
+        let expected = match Expected::load(year, day_num) {
+            Ok(expected) => expected,
+            Err(e) => {
+                reject(e);
+                continue;
+            }
+        };
+        let input = match get_input(year, day_num) {
+            Ok(input) => input,
+            Err(e) => {
+                reject(e.to_string());
+                continue;
+            }
+        };
+        // no point running a star there's nothing to check against
+        let stars = [false, true].map(|is_second_star| expected.star(is_second_star).is_some());
+        let mut worker = DayWorker::spawn_stars(day, input, stars, ARGS.timeout);
+        if let Err(e) = worker.parsed() {
+            reject(e.to_string());
+            continue;
+        }
+
+        for is_second_star in [false, true] {
+            let verdict = match expected.star(is_second_star) {
+                Some(answer) => Verdict::check(answer, worker.next_star().result),
+                None => Verdict::Unverified,
+            };
+            let star_verdict = StarVerdict {
+                day: day_num,
+                star: is_second_star as usize + 1,
+                verdict,
+            };
+            println!("{star_verdict}");
+            tally.add(&star_verdict.verdict);
+        }
+    }
+
+    println!("\n{}", display::banner());
+    println!("{tally}");
+
+    if tally.failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Leaked since stars run on their own threads that might outlive the day.
+/// It's needed until we exit anyway
+fn get_input(year: u16, day_num: usize) -> Result<&'static str, input::InputError> {
+    input::read(year, day_num, ARGS.input.as_deref()).map(|input| &*input.leak())
 }
 
-fn run_star(day: &dyn Day, input: String, is_second_star: bool) {
+/// Run a day without printing anything along the way
+fn solve_day(day: &'static dyn Solver, input: &'static str) -> Result<DayRun, SolveError> {
+    let mut worker = DayWorker::spawn(day, input, ARGS.timeout);
+    let parse = worker.parsed()?;
+    Ok(DayRun {
+        parse,
+        stars: [(); 2].map(|_| worker.next_star()),
+    })
+}
+
+/// Parse then run both stars on the same input, timing each step.
+/// Returns whatever answers the stars came up with
+fn run_day(day: &'static dyn Solver, input: &'static str) -> [Option<Answer>; 2] {
+    let mut worker = DayWorker::spawn(day, input, ARGS.timeout);
+    let parse_dur = match worker.parsed() {
+        Ok(parse_dur) => parse_dur,
+        Err(e) => {
+            report_error(&e, input);
+            return [None, None];
+        }
+    };
+    println!("{}: {parse_dur:?}", "Parsed in".green());
+    println!();
+
+    let star1 = run_star(day, &mut worker, input, false);
+    let star2 = run_star(day, &mut worker, input, true);
+    let (star1_dur, star2_dur) = (star1.elapsed, star2.elapsed);
+
+    println!(
+        "{} parse {parse_dur:?} + star 1 {star1_dur:?} + star 2 {star2_dur:?} = {:?}",
+        "Total:".green(),
+        parse_dur + star1_dur + star2_dur
+    );
+    [star1.result.ok(), star2.result.ok()]
+}
+
+fn run_star(
+    day: &'static dyn Solver,
+    worker: &mut DayWorker,
+    input: &str,
+    is_second_star: bool,
+) -> StarRun {
     let day_num = is_second_star as usize + 1;
 
+    if !day.implemented(is_second_star) {
+        let skipped = format!("Star {day_num} isn't implemented yet");
+        println!("{}\n", skipped.dimmed());
+        return worker.next_star();
+    }
+
     println!(
         "{} {} {}",
         "Running".green(),
@@ -95,31 +728,141 @@ fn run_star(day: &dyn Day, input: String, is_second_star: bool) {
         day_num.to_string().green()
     );
     println!("{}", display::banner());
-    let start = Instant::now();
-    let solution = match is_second_star {
-        false => day.star1(input),
-        true => day.star2(input),
-    };
-    println!("{} {solution}", "Solution:".green());
-    let dur = start.elapsed();
-    println!("{}: {dur:?}", "Took".green());
+    let run = worker.next_star();
+    match &run.result {
+        Ok(answer) if answer.is_multiline() => println!("{}\n{answer}", "Solution:".green()),
+        Ok(answer) => println!("{} {answer}", "Solution:".green()),
+        Err(e) => report_error(e, input),
+    }
+    println!("{}: {:?}", "Took".green(), run.elapsed);
     println!();
+    run
 }
 
-fn stress_test_star(day: &dyn Day, input: String, is_second_star: bool) {
-    let day_num = is_second_star as usize + 1;
-    let mut total = std::time::Duration::ZERO;
-    for _ in 0..ARGS.test_len {
-        total += time_star(day, input.clone(), is_second_star);
-    }
-    log!("Ran star {} in {:?}", day_num, total / ARGS.test_len);
+/// Print why a star failed. Parse errors point at the offending spot in the input
+fn report_error(err: &SolveError, input: &str) {
+    println!("{} {err}", "Error:".red());
+    let SolveError::Parse(parse_err) = err else {
+        return;
+    };
+    let Some(location) = parse_err.location(input) else {
+        return;
+    };
+    let line = input.lines().nth(location.line - 1).unwrap_or_default();
+    let gutter = " ".repeat(location.line.to_string().len());
+    println!(
+        "{gutter}{} line {}, column {}",
+        "-->".blue(),
+        location.line,
+        location.col
+    );
+    println!("{gutter} {}", "|".blue());
+    println!("{} {} {line}", location.line.to_string().blue(), "|".blue());
+    println!(
+        "{gutter} {} {}{}",
+        "|".blue(),
+        " ".repeat(location.col - 1),
+        "^".red()
+    );
 }
 
-fn time_star(day: &dyn Day, input: String, is_second_star: bool) -> std::time::Duration {
-    let start = std::time::Instant::now();
-    match is_second_star {
-        false => day.star1(input),
-        true => day.star2(input),
+/// Benchmark parsing and each star separately. None if the input doesn't parse
+fn stress_test_day(
+    year: u16,
+    day_num: usize,
+    day: &'static dyn Solver,
+    input: &'static str,
+) -> Option<DayBench> {
+    let config = BenchConfig {
+        warm_up: ARGS.warm_up,
+        runs: ARGS.test_len,
     };
-    start.elapsed()
+    let mut worker = DayWorker::spawn(day, input, ARGS.timeout);
+    if let Err(e) = worker.parsed() {
+        report_error(&e, input);
+        return None;
+    }
+    // both stars out of the way first so the worker isn't competing with the benchmarks
+    let first_runs = [(); 2].map(|_| worker.next_star());
+    // it already parsed fine on the worker
+    let puzzle = day.parse(input).ok()?;
+    let parse = config.run(|| day.parse(input));
+    log!("Parsed: {parse}");
+
+    let mut first_runs = first_runs.into_iter();
+    let stars = [false, true].map(|is_second_star| {
+        let first_run = first_runs.next().unwrap();
+        stress_test_star(day, &*puzzle, first_run, input, is_second_star, config)
+    });
+    Some(DayBench {
+        year,
+        day: day_num,
+        parse,
+        stars,
+    })
+}
+
+/// None if the star doesn't produce an answer. Timing how fast it fails isn't very useful.
+///
+/// Only `first_run` gets the worker thread treatment, the rest run here so
+/// spawning threads doesn't end up in the numbers
+fn stress_test_star(
+    day: &'static dyn Solver,
+    puzzle: &dyn Puzzle,
+    first_run: StarRun,
+    input: &str,
+    is_second_star: bool,
+    config: BenchConfig,
+) -> Option<Stats> {
+    let star_num = is_second_star as usize + 1;
+    if !day.implemented(is_second_star) {
+        log!("Star {star_num}: not implemented");
+        return None;
+    }
+    if let Err(e) = first_run.result {
+        report_error(&e, input);
+        return None;
+    }
+    let stats = config.run(|| match is_second_star {
+        false => puzzle.star1(),
+        true => puzzle.star2(),
+    });
+    log!("Star {star_num}: {stats}");
+    Some(stats)
+}
+
+/// Write out the results, compare them to an earlier run if asked then add them to the history
+fn finish_stress_test(benches: Vec<DayBench>) {
+    if benches.is_empty() {
+        return;
+    }
+    if let Some(path) = &ARGS.json {
+        match bench::write_json(path, &benches) {
+            Ok(()) => log!("Wrote benchmarks to {}", path.display()),
+            Err(e) => println!("{} Could not write {}: {e}", "Error:".red(), path.display()),
+        }
+    }
+
+    let path = env::current_dir().unwrap().join(history::HISTORY_FILE);
+    let run = BenchRun::new(ARGS.save_baseline.clone(), benches);
+
+    if let Some(baseline) = &ARGS.compare {
+        match history::load(&path) {
+            Ok(runs) => {
+                let comparison = Comparison::new(&run, &runs, baseline.as_deref(), ARGS.threshold);
+                match baseline {
+                    Some(name) => println!("\n{} `{name}`", "Compared to".green()),
+                    None => println!("\n{} the previous run", "Compared to".green()),
+                }
+                println!("{}", display::banner());
+                print!("{comparison}");
+            }
+            Err(e) => println!("{} {e}", "Error:".red()),
+        }
+    }
+
+    match history::append(&path, &run) {
+        Ok(()) => log!("Saved run {run} to {}", path.display()),
+        Err(e) => println!("{} Could not write {}: {e}", "Error:".red(), path.display()),
+    }
 }
@@ -0,0 +1,223 @@
+//! Fallible parsing of puzzle input
+
+use std::{
+    char::ParseCharError,
+    convert::Infallible,
+    fmt::Display,
+    marker::PhantomData,
+    num::{
+        ParseFloatError,
+        ParseIntError,
+    },
+    str::{
+        FromStr,
+        Lines,
+    },
+};
+
+/// Turns raw puzzle input into a day's [`Day::Input`](crate::days::Day::Input)
+pub trait Parse<'a>: Sized {
+    fn parse(input: &'a str) -> Result<Self, ParseError>;
+}
+
+/// For days that would rather work on the raw text
+impl<'a> Parse<'a> for &'a str {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(input)
+    }
+}
+
+/// One item per line
+impl<'a, T> Parse<'a> for Vec<T>
+where
+    T: FromStr<Err = ParseError>,
+{
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        input.try_parsed_lines().collect()
+    }
+}
+
+/// Input that could not be parsed.
+///
+/// Remembers which slice of the input it was raised on so the runner can
+/// point at the offending line and column. The span is only ever compared
+/// against the input, never dereferenced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    msg: String,
+    span: Option<Span>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Span {
+    addr: usize,
+    len: usize,
+}
+
+/// 1 based line and column of a [`ParseError`] within the puzzle input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl ParseError {
+    pub fn new(msg: impl Into<String>) -> Self {
+        Self {
+            msg: msg.into(),
+            span: None,
+        }
+    }
+
+    /// error raised on `fragment`, which should be a slice of the puzzle input
+    pub fn at(fragment: &str, msg: impl Into<String>) -> Self {
+        Self::new(msg).or_at(fragment)
+    }
+
+    /// attach `fragment` as the location unless we already know a more precise one
+    pub fn or_at(mut self, fragment: &str) -> Self {
+        self.span.get_or_insert(Span {
+            addr: fragment.as_ptr() as usize,
+            len: fragment.len(),
+        });
+        self
+    }
+
+    /// Where in `input` this error happened.
+    ///
+    /// None if the error has no span or the span points outside of `input`
+    pub fn location(&self, input: &str) -> Option<Location> {
+        let span = self.span?;
+        let start = input.as_ptr() as usize;
+        let offset = span.addr.checked_sub(start)?;
+        if offset + span.len > input.len() || !input.is_char_boundary(offset) {
+            return None;
+        }
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some(Location {
+            line: before.matches('\n').count() + 1,
+            col: before[line_start..].chars().count() + 1,
+        })
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Fallible versions of the str methods the days lean on.
+/// Errors point at the str they were called on.
+pub trait StrParseExt {
+    fn try_parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Into<ParseError>;
+
+    fn try_split_once(&self, delim: &str) -> Result<(&str, &str), ParseError>;
+
+    fn try_strip_prefix(&self, prefix: &str) -> Result<&str, ParseError>;
+
+    fn try_strip_suffix(&self, suffix: &str) -> Result<&str, ParseError>;
+
+    fn try_parsed_lines<T>(&self) -> TryParsedIter<Lines<'_>, T>;
+
+    /// convert each char, pointing at the offending char on failure
+    fn try_parse_chars<T>(&self) -> impl Iterator<Item = Result<T, ParseError>>
+    where
+        T: TryFrom<char>,
+        T::Error: Into<ParseError>;
+}
+
+impl StrParseExt for str {
+    fn try_parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Into<ParseError>,
+    {
+        self.parse().map_err(|e: T::Err| e.into().or_at(self))
+    }
+
+    fn try_split_once(&self, delim: &str) -> Result<(&str, &str), ParseError> {
+        self.split_once(delim)
+            .ok_or_else(|| ParseError::at(self, format!("expected `{delim}` in `{self}`")))
+    }
+
+    fn try_strip_prefix(&self, prefix: &str) -> Result<&str, ParseError> {
+        self.strip_prefix(prefix).ok_or_else(|| {
+            ParseError::at(self, format!("expected `{self}` to start with `{prefix}`"))
+        })
+    }
+
+    fn try_strip_suffix(&self, suffix: &str) -> Result<&str, ParseError> {
+        self.strip_suffix(suffix).ok_or_else(|| {
+            ParseError::at(self, format!("expected `{self}` to end with `{suffix}`"))
+        })
+    }
+
+    fn try_parsed_lines<T>(&self) -> TryParsedIter<Lines<'_>, T> {
+        TryParsedIter(self.lines(), PhantomData)
+    }
+
+    fn try_parse_chars<T>(&self) -> impl Iterator<Item = Result<T, ParseError>>
+    where
+        T: TryFrom<char>,
+        T::Error: Into<ParseError>,
+    {
+        self.char_indices().map(|(i, c)| {
+            T::try_from(c).map_err(|e: T::Error| e.into().or_at(&self[i..i + c.len_utf8()]))
+        })
+    }
+}
+
+pub trait TryParseEachExt: Iterator {
+    /// Like [`parse_each`](crate::ext::IterExt::parse_each) but yields a [`ParseError`]
+    /// pointing at the bad item instead of panicking
+    fn try_parse_each<T>(self) -> TryParsedIter<Self, T>
+    where
+        Self: Sized,
+    {
+        TryParsedIter(self, PhantomData)
+    }
+}
+
+impl<I: Iterator> TryParseEachExt for I {}
+
+pub struct TryParsedIter<I, T>(I, PhantomData<T>);
+
+impl<I, T> Iterator for TryParsedIter<I, T>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+    T: FromStr,
+    T::Err: Into<ParseError>,
+{
+    type Item = Result<T, ParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|item| item.as_ref().try_parse())
+    }
+}
+
+impl From<Infallible> for ParseError {
+    fn from(value: Infallible) -> Self {
+        match value {}
+    }
+}
+
+macro_rules! from_std_errors {
+    ($($err:ty),*) => {
+        $(
+            impl From<$err> for ParseError {
+                fn from(e: $err) -> Self {
+                    Self::new(e.to_string())
+                }
+            }
+        )*
+    };
+}
+
+from_std_errors!(ParseIntError, ParseFloatError, ParseCharError);
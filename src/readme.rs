@@ -0,0 +1,160 @@
+//! Keeping the stars table and badges in `README.md` up to date with `aoc readme`
+//!
+//! A star counts once Advent of Code has accepted its answer, going by
+//! `answers/` and the submissions ledger, and the day still implements it.
+
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+};
+
+use chrono::Datelike;
+use lazy_static::lazy_static;
+use regex::{
+    Captures,
+    Regex,
+};
+
+use crate::{
+    days::Solver,
+    scaffold,
+    submit::ledger::Ledger,
+    verify::Expected,
+};
+
+pub const README: &str = "README.md";
+
+/// What the table sits between. Same marker the advent-readme-stars action uses
+const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Which stars each day of a year has earned
+pub struct Stars {
+    pub year: u16,
+    pub days: Vec<(usize, [bool; 2])>,
+}
+
+impl Stars {
+    pub fn collect(year: u16) -> Result<Self, String> {
+        let ledger = Ledger::load(year)?;
+        let mut days = Vec::new();
+        for (day_year, day_num, day) in macros::all_days!() {
+            if day_year != year {
+                continue;
+            }
+            let expected = Expected::load(year, day_num)?;
+            let stars = [1, 2].map(|star| {
+                let accepted =
+                    expected.star(star == 2).is_some() || ledger.solved(day_num, star).is_some();
+                accepted && day.implemented(star == 2)
+            });
+            days.push((day_num, stars));
+        }
+        Ok(Self { year, days })
+    }
+
+    pub fn total(&self) -> usize {
+        self.days
+            .iter()
+            .flat_map(|(_, stars)| stars)
+            .filter(|star| **star)
+            .count()
+    }
+
+    pub fn completed_days(&self) -> usize {
+        self.days
+            .iter()
+            .filter(|(_, stars)| stars.iter().all(|star| *star))
+            .count()
+    }
+
+    /// Days unlocked so far, which is all of them once the year is over
+    fn unlocked(&self) -> u32 {
+        let now = scaffold::now();
+        match (self.year as i32).cmp(&now.year()) {
+            Ordering::Less => 25,
+            Ordering::Greater => 0,
+            Ordering::Equal if now.month() == 12 => now.day().min(25),
+            Ordering::Equal => 0,
+        }
+    }
+
+    /// `README.md` with the table and badges swapped for these stars
+    pub fn update(&self, readme: &str) -> Result<String, String> {
+        let parts = readme.split(MARKER).collect::<Vec<_>>();
+        let [before, _, after] = parts[..] else {
+            return Err(format!(
+                "{README} needs the table between two `{MARKER}` lines"
+            ));
+        };
+
+        lazy_static! {
+            static ref BADGE: Regex = Regex::new(
+                r"(https://img\.shields\.io/badge/(day%20📅|stars%20⭐|days%20completed))-\d+-"
+            )
+            .unwrap();
+        }
+        let badges = |text: &str| {
+            BADGE
+                .replace_all(text, |caps: &Captures| {
+                    let count = match &caps[2] {
+                        "day%20📅" => self.unlocked() as usize,
+                        "stars%20⭐" => self.total(),
+                        _ => self.completed_days(),
+                    };
+                    format!("{}-{count}-", &caps[1])
+                })
+                .into_owned()
+        };
+
+        Ok(format!(
+            "{}{MARKER}\n{self}{MARKER}{}",
+            badges(before),
+            badges(after)
+        ))
+    }
+}
+
+impl Display for Stars {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "## {} Results\n", self.year)?;
+        writeln!(f, "| Day | Part 1 | Part 2 |")?;
+        writeln!(f, "| :---: | :---: | :---: |")?;
+        for (day, stars) in &self.days {
+            let [star1, star2] = stars.map(|star| if star { "⭐" } else { " " });
+            writeln!(
+                f,
+                "| [Day {day}](https://adventofcode.com/{}/day/{day}) | {star1} | {star2} |",
+                self.year
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rewrites_between_markers() {
+        let readme = format!(
+            "# Title\n![](https://img.shields.io/badge/stars%20⭐-2-yellow)\n\
+             ![](https://img.shields.io/badge/days%20completed-1-red)\n\
+             {MARKER}\nstale table\n{MARKER}\nthe end\n"
+        );
+        let stars = Stars {
+            year: 2023,
+            days: vec![(1, [true, true]), (2, [true, true]), (3, [true, false])],
+        };
+
+        let updated = stars.update(&readme).unwrap();
+        assert!(updated.contains("stars%20⭐-5-yellow"));
+        assert!(updated.contains("days%20completed-2-red"));
+        assert!(!updated.contains("stale table"));
+        assert!(updated.contains("| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ |   |\n"));
+        assert!(updated.ends_with(&format!("{MARKER}\nthe end\n")));
+
+        // running it again changes nothing
+        assert_eq!(stars.update(&updated).unwrap(), updated);
+    }
+}
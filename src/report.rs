@@ -0,0 +1,287 @@
+//! `--format` output for feeding runs into spreadsheets and dashboards
+//!
+//! One record per star. Every record carries the environment it ran in so rows
+//! from different machines and commits can be thrown in the same sheet.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{
+    bench::history,
+    days::SolveError,
+    summary::{
+        DaySummary,
+        StarRun,
+    },
+};
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    /// The day couldn't be run or the star came back with an error
+    Error,
+    Unimplemented,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Error => "error",
+            Self::Unimplemented => "unimplemented",
+            Self::Panicked => "panicked",
+            Self::TimedOut => "timed_out",
+        }
+    }
+}
+
+/// Where and when the results came from
+#[derive(Debug, Serialize)]
+pub struct Environment {
+    pub timestamp: String,
+    pub commit: String,
+    pub version: &'static str,
+    /// `debug` or `release`
+    pub profile: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub threads: usize,
+}
+
+impl Environment {
+    pub fn current() -> Self {
+        Self {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            commit: history::current_commit(),
+            version: env!("CARGO_PKG_VERSION"),
+            profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            },
+            os: std::env::consts::OS,
+            arch: std::env::consts::ARCH,
+            threads: std::thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: usize,
+    pub star: usize,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// what went wrong if the status isn't ok
+    pub error: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub duration_ns: Option<u64>,
+}
+
+impl Record {
+    fn new(year: u16, day: usize, star: usize, parse: Duration, run: &StarRun) -> Self {
+        let status = match &run.result {
+            Ok(_) => Status::Ok,
+            Err(SolveError::Unimplemented) => Status::Unimplemented,
+            Err(SolveError::Panicked(_)) => Status::Panicked,
+            Err(SolveError::TimedOut(_)) => Status::TimedOut,
+            Err(_) => Status::Error,
+        };
+        Self {
+            year,
+            day,
+            star,
+            status,
+            answer: run.result.as_ref().ok().map(ToString::to_string),
+            error: run.result.as_ref().err().map(ToString::to_string),
+            parse_ns: Some(parse.as_nanos() as u64),
+            duration_ns: (status != Status::Unimplemented).then_some(run.elapsed.as_nanos() as u64),
+        }
+    }
+
+    /// Both stars of a day that never got as far as running
+    fn failed(year: u16, day: usize, star: usize, why: &str) -> Self {
+        Self {
+            year,
+            day,
+            star,
+            status: Status::Error,
+            answer: None,
+            error: Some(why.to_string()),
+            parse_ns: None,
+            duration_ns: None,
+        }
+    }
+}
+
+pub fn records(year: u16, summaries: &[DaySummary]) -> Vec<Record> {
+    summaries
+        .iter()
+        .flat_map(|summary| {
+            [1, 2].map(|star| match &summary.run {
+                Ok(run) => Record::new(year, summary.day, star, run.parse, &run.stars[star - 1]),
+                Err(why) => Record::failed(year, summary.day, star, why),
+            })
+        })
+        .collect()
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    environment: &'a Environment,
+    results: &'a [Record],
+}
+
+/// Everything ready to print, ending in a newline
+pub fn render(format: Format, env: &Environment, records: &[Record]) -> String {
+    let separator = match format {
+        Format::Json => {
+            let report = JsonReport {
+                environment: env,
+                results: records,
+            };
+            return serde_json::to_string_pretty(&report).unwrap() + "\n";
+        }
+        Format::Csv => ',',
+        Format::Tsv => '\t',
+    };
+
+    let header = [
+        "year",
+        "day",
+        "star",
+        "status",
+        "answer",
+        "error",
+        "parse_ns",
+        "duration_ns",
+        "timestamp",
+        "commit",
+        "version",
+        "profile",
+        "os",
+        "arch",
+        "threads",
+    ];
+    let mut out = header.join(&separator.to_string()) + "\n";
+    for record in records {
+        let opt = |n: Option<u64>| n.map(|n| n.to_string()).unwrap_or_default();
+        let row = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.star.to_string(),
+            record.status.name().to_string(),
+            record.answer.clone().unwrap_or_default(),
+            record.error.clone().unwrap_or_default(),
+            opt(record.parse_ns),
+            opt(record.duration_ns),
+            env.timestamp.clone(),
+            env.commit.clone(),
+            env.version.to_string(),
+            env.profile.to_string(),
+            env.os.to_string(),
+            env.arch.to_string(),
+            env.threads.to_string(),
+        ];
+        let row = row.iter().map(|field| match format {
+            Format::Tsv => escape_tsv(field),
+            _ => escape_csv(field),
+        });
+        out += &itertools::join(row, &separator.to_string());
+        out.push('\n');
+    }
+    out
+}
+
+/// Quoted if it has to be, with quotes doubled. Pictures keep their newlines
+fn escape_csv(field: &str) -> String {
+    match field.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", field.replace('"', "\"\"")),
+        false => field.to_string(),
+    }
+}
+
+/// TSV can't quote so tabs and newlines get backslash escaped instead
+fn escape_tsv(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        answer::Answer,
+        summary::DayRun,
+    };
+
+    fn env() -> Environment {
+        Environment {
+            timestamp: "2023-12-25T00:00:00-05:00".into(),
+            commit: "abc1234".into(),
+            version: "0.1.0",
+            profile: "release",
+            os: "linux",
+            arch: "x86_64",
+            threads: 8,
+        }
+    }
+
+    fn summaries() -> Vec<DaySummary> {
+        let star = |result| StarRun {
+            result,
+            elapsed: Duration::from_nanos(1500),
+        };
+        vec![
+            DaySummary {
+                day: 10,
+                run: Ok(DayRun {
+                    parse: Duration::from_nanos(200),
+                    stars: [
+                        star(Ok(Answer::Str("a,\"b\"".into()))),
+                        star(Err(SolveError::Unimplemented)),
+                    ],
+                }),
+            },
+            DaySummary {
+                day: 11,
+                run: Err("no input".into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn csv_quotes_awkward_answers() {
+        let csv = render(Format::Csv, &env(), &records(2023, &summaries()));
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("year,day,star,status,answer,error,parse_ns,duration_ns,"));
+        assert!(lines[1].starts_with(r#"2023,10,1,ok,"a,""b""",,200,1500,"#));
+        assert!(lines[2].starts_with("2023,10,2,unimplemented,,Unimplemented,200,,"));
+        assert!(lines[3].starts_with("2023,11,1,error,,no input,,,"));
+    }
+
+    #[test]
+    fn json_has_environment() {
+        let json = render(Format::Json, &env(), &records(2023, &summaries()));
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["environment"]["commit"], "abc1234");
+        assert_eq!(value["results"][1]["status"], "unimplemented");
+        assert_eq!(value["results"][0]["duration_ns"], 1500);
+    }
+}
@@ -0,0 +1,128 @@
+//! Setting up a new day. Safe to run again on a day that already exists
+
+use std::{
+    fs,
+    io,
+    path::Path,
+    process::Command,
+};
+
+use chrono::{
+    Datelike,
+    FixedOffset,
+    Utc,
+};
+use colored::Colorize;
+
+const TEMPLATE: &str = include_str!("scaffold/day.rs.template");
+const YEARS_DIR: &str = "src/years";
+const YEARS_MOD_PATH: &str = "src/years.rs";
+
+/// Puzzles unlock at midnight EST so that's the day we want
+pub fn now() -> chrono::DateTime<FixedOffset> {
+    let est = FixedOffset::west_opt(5 * 60 * 60).unwrap();
+    Utc::now().with_timezone(&est)
+}
+
+pub fn today() -> usize {
+    now().day() as usize
+}
+
+pub fn this_year() -> u16 {
+    now().year() as u16
+}
+
+pub fn scaffold(year: u16, day_num: usize, open: bool) -> io::Result<()> {
+    if !Path::new(YEARS_DIR).is_dir() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{YEARS_DIR} not found. Run this from the root of the repo"),
+        ));
+    }
+    let day = format!("{day_num:02}");
+
+    let year_dir = format!("{YEARS_DIR}/y{year}");
+    fs::create_dir_all(&year_dir)?;
+    let template = TEMPLATE
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day);
+    create(&format!("{year_dir}/day{day}.rs"), &template)?;
+    let year_mod_path = format!("{year_dir}.rs");
+    if !Path::new(&year_mod_path).exists() {
+        fs::write(&year_mod_path, format!("//! Advent of Code {year}\n\n"))?;
+    }
+    register_mod(YEARS_MOD_PATH, &format!("y{year}"))?;
+    register_mod(&year_mod_path, &format!("day{day}"))?;
+
+    let examples_dir = format!("inputs/{year}/examples");
+    fs::create_dir_all(&examples_dir)?;
+    create(&format!("{examples_dir}/day{day}_1.txt"), "")?;
+    create(
+        &format!("{examples_dir}/day{day}_1.toml"),
+        "# star1 = \n# star2 = \n",
+    )?;
+
+    // downloads it if it can
+    if let Err(e) = crate::input::read(year, day_num, None) {
+        println!("{} {e:#}", "Todo:".yellow());
+    }
+
+    let url = format!("https://adventofcode.com/{year}/day/{day_num}");
+    println!("{} {url}", "Puzzle:".green());
+    if open {
+        open_in_browser(&url);
+    }
+    Ok(())
+}
+
+/// Write `contents` to `path` unless there is already something there
+fn create(path: &str, contents: &str) -> io::Result<()> {
+    if Path::new(path).exists() {
+        println!("{} {path} already exists", "Skipping:".yellow());
+        return Ok(());
+    }
+    fs::write(path, contents)?;
+    println!("{} {path}", "Created:".green());
+    Ok(())
+}
+
+/// Add `pub mod {name};` to the file at `path`, keeping the mods in order
+fn register_mod(path: &str, name: &str) -> io::Result<()> {
+    let mods = fs::read_to_string(path)?;
+    let new_mod = format!("pub mod {name};");
+    let mut lines = mods.lines().collect::<Vec<_>>();
+    if lines.contains(&new_mod.as_str()) {
+        println!("{} {name} is already in {path}", "Skipping:".yellow());
+        return Ok(());
+    }
+
+    // years and zero padded days both sort as strings
+    let is_mod = |line: &&str| line.starts_with("pub mod ");
+    let index = lines
+        .iter()
+        .position(|line| is_mod(line) && *line > new_mod.as_str())
+        .or_else(|| lines.iter().rposition(is_mod).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(index, &new_mod);
+
+    let mut mods = lines.join("\n");
+    mods.push('\n');
+    fs::write(path, mods)?;
+    println!("{} {name} in {path}", "Registered:".green());
+    Ok(())
+}
+
+/// Best effort. The link has already been printed if this doesn't work
+fn open_in_browser(url: &str) {
+    let opener = match std::env::consts::OS {
+        "macos" => "open",
+        "windows" => "explorer",
+        _ => "xdg-open",
+    };
+    if let Err(e) = Command::new(opener).arg(url).status() {
+        println!(
+            "{} could not open a browser with {opener}: {e}",
+            "Warning:".yellow()
+        );
+    }
+}
@@ -0,0 +1,353 @@
+//! Searching any graph given a start state and a successor function
+//!
+//! States can be whatever the puzzle needs, like a position along with how far
+//! it's gone in a straight line. Every search stops as soon as `is_goal` says so,
+//! or once it runs out of states, and hands back a [`Search`] of what it found.
+//! `is_goal` sees every state once, along with its distance from the start, so
+//! it doubles as a hook for watching the search. [`astar`] shows a state again
+//! if it finds a cheaper way there later.
+
+use std::{
+    cmp::Reverse,
+    collections::{
+        hash_map::Entry,
+        BinaryHeap,
+        VecDeque,
+    },
+    hash::Hash,
+};
+
+use rustc_hash::FxHashMap;
+
+/// Everything a search reached and how it got there
+pub struct Search<S> {
+    /// hashed with rustc's hasher, the default one takes most of the time on the
+    /// millions of small states like grid indices a search goes through
+    reached: FxHashMap<S, Reached<S>>,
+    goal: Option<S>,
+}
+
+struct Reached<S> {
+    /// steps for [`bfs`] and [`dfs`], total cost for [`dijkstra`] and [`astar`]
+    distance: usize,
+    /// The start doesn't have one
+    prev: Option<S>,
+    /// `false` while it's waiting in [`astar`]'s queue
+    settled: bool,
+}
+
+impl<S> Reached<S> {
+    fn settled(distance: usize, prev: Option<S>) -> Self {
+        Self {
+            distance,
+            prev,
+            settled: true,
+        }
+    }
+}
+
+impl<S: Hash + Eq + Clone> Search<S> {
+    fn new() -> Self {
+        Self {
+            reached: FxHashMap::default(),
+            goal: None,
+        }
+    }
+
+    /// The first state `is_goal` said yes to
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// How far it is from the start to the goal
+    pub fn goal_distance(&self) -> Option<usize> {
+        self.distance(self.goal()?)
+    }
+
+    pub fn distance(&self, state: &S) -> Option<usize> {
+        self.reached
+            .get(state)
+            .filter(|reached| reached.settled)
+            .map(|reached| reached.distance)
+    }
+
+    /// Every state the search got to
+    pub fn visited(&self) -> impl Iterator<Item = &S> {
+        self.reached
+            .iter()
+            .filter(|(_, reached)| reached.settled)
+            .map(|(state, _)| state)
+    }
+
+    pub fn was_visited(&self, state: &S) -> bool {
+        self.distance(state).is_some()
+    }
+
+    /// From the start to `state`, both included
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.was_visited(state) {
+            return None;
+        }
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.reached[path.last().unwrap()].prev.clone() {
+            path.push(prev);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// From the start to the goal, both included
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.path_to(self.goal()?)
+    }
+}
+
+/// Fewest steps to every state, one step at a time
+#[allow(unused)] // until a day needs it
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S, usize) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    search
+        .reached
+        .insert(start.clone(), Reached::settled(0, None));
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = search.reached[&state].distance;
+        if is_goal(&state, distance) {
+            search.goal = Some(state);
+            break;
+        }
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = search.reached.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(Reached::settled(distance + 1, Some(state.clone())));
+            }
+        }
+    }
+    search
+}
+
+/// Goes as deep as it can before backing up, with every one of `starts` at depth 0.
+/// Distances are how deep it went to find each state rather than the fewest steps there
+pub fn dfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S, usize) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut stack = starts
+        .into_iter()
+        .map(|start| (start, 0, None))
+        .collect::<Vec<_>>();
+    while let Some((state, depth, prev)) = stack.pop() {
+        let Entry::Vacant(entry) = search.reached.entry(state.clone()) else {
+            continue;
+        };
+        entry.insert(Reached::settled(depth, prev));
+        if is_goal(&state, depth) {
+            search.goal = Some(state);
+            break;
+        }
+        for next in successors(&state) {
+            if !search.reached.contains_key(&next) {
+                stack.push((next, depth + 1, Some(state.clone())));
+            }
+        }
+    }
+    search
+}
+
+/// Cheapest way to every state where `successors` gives the cost of each step
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S, usize) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// [`dijkstra`] trying the states `heuristic` thinks are closest to the goal first.
+/// It has to never guess more than the real cost or the answer might not be the cheapest.
+/// If the guess drops by more than a step costs, a state can be visited before the
+/// cheapest way there turns up. It gets visited again when it does
+pub fn astar<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut is_goal: impl FnMut(&S, usize) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    search.reached.insert(
+        start.clone(),
+        Reached {
+            distance: 0,
+            prev: None,
+            settled: false,
+        },
+    );
+    // the queue only holds indexes into this so states don't need to be `Ord`
+    let mut queued = vec![Some(start.clone())];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = queued[index].take().unwrap();
+        let reached = search.reached.get_mut(&state).unwrap();
+        // found somewhere cheaper after this was queued
+        if reached.settled || reached.distance < cost {
+            continue;
+        }
+        reached.settled = true;
+        if is_goal(&state, cost) {
+            search.goal = Some(state);
+            break;
+        }
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            let cheaper = Reached {
+                distance: next_cost,
+                prev: Some(state.clone()),
+                settled: false,
+            };
+            let next = match search.reached.entry(next) {
+                Entry::Occupied(entry) if entry.get().distance <= next_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(cheaper);
+                    entry.key().clone()
+                }
+                Entry::Vacant(entry) => {
+                    let next = entry.key().clone();
+                    entry.insert(cheaper);
+                    next
+                }
+            };
+            queue.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                queued.len(),
+            )));
+            queued.push(Some(next));
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collections::{
+        Grid,
+        Index,
+    };
+    use itertools::Itertools;
+
+    fn maze() -> Grid<char> {
+        "S.#.\n\
+         .##.\n\
+         ...E"
+            .parse()
+            .unwrap()
+    }
+
+    fn open(maze: &Grid<char>) -> impl FnMut(&Index) -> Vec<Index> + '_ {
+        |i| {
+            maze.neighbors4(*i)
+                .filter(|next| maze[*next] != '#')
+                .collect()
+        }
+    }
+
+    #[test]
+    fn bfs_and_dfs_find_the_exit() {
+        let maze = maze();
+        let found = bfs((0, 0), open(&maze), |i, _| maze[*i] == 'E');
+        assert_eq!(found.goal_distance(), Some(5));
+        assert_eq!(
+            found.path().unwrap(),
+            [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)]
+        );
+
+        let found = dfs([(0, 0)], open(&maze), |i, _| maze[*i] == 'E');
+        assert_eq!(found.goal(), Some(&(2, 3)));
+        assert_eq!(
+            found.path().unwrap().len() - 1,
+            found.goal_distance().unwrap()
+        );
+
+        // nothing stops it so it goes everywhere it can
+        let everywhere = dfs([(0, 0)], open(&maze), |_, _| false);
+        assert_eq!(everywhere.visited().count(), 9);
+    }
+
+    #[test]
+    fn cheapest_path_costs_agree() {
+        // going through a `9` costs more than going around it
+        let costs =
+            Grid::<u8>::try_from(vec![vec![1, 9, 1], vec![1, 9, 1], vec![1, 1, 1]]).unwrap();
+        let successors = |i: &Index| {
+            costs
+                .neighbors4(*i)
+                .map(|next| (next, costs[next] as usize))
+                .collect_vec()
+        };
+        let is_goal = |i: &Index, _| *i == (0, 2);
+
+        let dijkstra = dijkstra((0, 0), successors, is_goal);
+        let astar = astar(
+            (0, 0),
+            successors,
+            |i| i.0.abs_diff(0) + i.1.abs_diff(2),
+            is_goal,
+        );
+        assert_eq!(dijkstra.goal_distance(), Some(6));
+        assert_eq!(astar.goal_distance(), Some(6));
+        assert_eq!(dijkstra.path(), astar.path());
+        assert!(astar.visited().count() <= dijkstra.visited().count());
+    }
+
+    #[test]
+    fn astar_revisits_states_it_finds_cheaper() {
+        let successors = |state: &char| match state {
+            'S' => vec![('A', 1), ('B', 2)],
+            'A' => vec![('C', 3)],
+            'B' => vec![('C', 1)],
+            'C' => vec![('G', 3)],
+            _ => vec![],
+        };
+        // never more than the real cost but it drops by 3 on a step that costs 1,
+        // so `C` gets visited the long way round first
+        let heuristic = |state: &char| match state {
+            'B' => 3,
+            _ => 0,
+        };
+        let mut seen = Vec::new();
+        let found = astar('S', successors, heuristic, |state, distance| {
+            seen.push((*state, distance));
+            *state == 'G'
+        });
+        assert_eq!(found.goal_distance(), Some(6));
+        assert_eq!(found.path().unwrap(), ['S', 'B', 'C', 'G']);
+        assert_eq!(
+            seen,
+            [('S', 0), ('A', 1), ('C', 4), ('B', 2), ('C', 3), ('G', 6)]
+        );
+    }
+}
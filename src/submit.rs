@@ -0,0 +1,414 @@
+//! Sending answers to Advent of Code with `--submit`
+//!
+//! Every attempt goes in the [`Ledger`] first so we never send an answer that
+//! was already wrong, or one that can't be right going by earlier
+//! "too high" / "too low" responses.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io,
+};
+
+use colored::Colorize;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    answer::Answer,
+    display,
+    input::provider::{
+        self,
+        HttpFetcher,
+    },
+    logging::log,
+    verify::Expected,
+};
+
+pub mod ledger;
+
+use ledger::{
+    Attempt,
+    Check,
+    Ledger,
+};
+
+/// What Advent of Code made of an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong and no hint which way
+    Wrong,
+    /// Answered too soon after the last one. Says nothing about the answer
+    RateLimited,
+    /// The star was already solved, or the one before it wasn't yet
+    WrongLevel,
+}
+
+impl Outcome {
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outcome = match self {
+            Self::Correct => "correct".green(),
+            Self::TooHigh => "too high".red(),
+            Self::TooLow => "too low".red(),
+            Self::Wrong => "wrong".red(),
+            Self::RateLimited => "rate limited".yellow(),
+            Self::WrongLevel => "wrong level".yellow(),
+        };
+        write!(f, "{outcome}")
+    }
+}
+
+#[derive(Debug)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// What the page said, without the html
+    pub message: String,
+}
+
+impl Response {
+    /// Makes sense of the page Advent of Code sends back after submitting
+    pub fn parse(html: &str) -> Option<Self> {
+        lazy_static! {
+            static ref ARTICLE: Regex = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+            static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+        }
+        let article = ARTICLE
+            .captures(html)
+            .map_or(html, |caps| caps.get(1).unwrap().as_str());
+        let message = TAG
+            .replace_all(article, "")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let outcome = match &message {
+            m if m.contains("That's the right answer") => Outcome::Correct,
+            m if m.contains("your answer is too high") => Outcome::TooHigh,
+            m if m.contains("your answer is too low") => Outcome::TooLow,
+            m if m.contains("That's not the right answer") => Outcome::Wrong,
+            m if m.contains("You gave an answer too recently") => Outcome::RateLimited,
+            m if m.contains("You don't seem to be solving the right level") => Outcome::WrongLevel,
+            _ => return None,
+        };
+        Some(Self { outcome, message })
+    }
+}
+
+pub trait Submitter {
+    /// `star` is 1 or 2, which Advent of Code calls the level
+    fn submit(&self, year: u16, day: usize, star: usize, answer: &str) -> io::Result<Response>;
+}
+
+/// Posts answers to adventofcode.com, or anything that answers like it does
+pub struct HttpSubmitter {
+    base_url: String,
+    session: String,
+}
+
+impl HttpSubmitter {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            session: session.into(),
+        }
+    }
+
+    /// Talks to adventofcode.com if we have a [`provider::session`]
+    pub fn from_env() -> Option<Self> {
+        provider::session().map(|session| Self::new(HttpFetcher::AOC_URL, session))
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, year: u16, day: usize, star: usize, answer: &str) -> io::Result<Response> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        log!("Submitting {answer} to {url}\n");
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", provider::USER_AGENT)
+            .send_form(&[("level", &star.to_string()), ("answer", answer)]);
+        let html = match response {
+            Ok(response) => response.into_string()?,
+            Err(ureq::Error::Status(400 | 500, _)) => {
+                return Err(io::Error::other(
+                    "the session cookie was rejected, it has probably expired",
+                ))
+            }
+            Err(ureq::Error::Status(code, response)) => {
+                return Err(io::Error::other(format!(
+                    "got {code} {}",
+                    response.status_text()
+                )))
+            }
+            Err(e) => return Err(io::Error::other(e)),
+        };
+        Response::parse(&html)
+            .ok_or_else(|| io::Error::other("could not make sense of the response"))
+    }
+}
+
+/// Plays Advent of Code using answers we already know, like the ones in `answers/`.
+/// Good for trying `--submit` out without using up real attempts
+#[derive(Default)]
+pub struct MockSubmitter {
+    accepted: HashMap<(u16, usize, usize), String>,
+}
+
+impl MockSubmitter {
+    pub fn from_answers(year: u16, day: usize) -> Result<Self, String> {
+        let expected = Expected::load(year, day)?;
+        let mut mock = Self::default();
+        for star in [1, 2] {
+            if let Some(answer) = expected.star(star == 2) {
+                mock = mock.accept(year, day, star, &answer);
+            }
+        }
+        Ok(mock)
+    }
+
+    pub fn accept(mut self, year: u16, day: usize, star: usize, answer: &str) -> Self {
+        self.accepted.insert((year, day, star), answer.to_string());
+        self
+    }
+}
+
+impl Submitter for MockSubmitter {
+    fn submit(&self, year: u16, day: usize, star: usize, answer: &str) -> io::Result<Response> {
+        let Some(accepted) = self.accepted.get(&(year, day, star)) else {
+            return Err(io::Error::other("no accepted answer to compare against"));
+        };
+        let outcome = match (answer.parse::<i128>(), accepted.parse::<i128>()) {
+            _ if answer == accepted => Outcome::Correct,
+            (Ok(answer), Ok(accepted)) if answer > accepted => Outcome::TooHigh,
+            (Ok(_), Ok(_)) => Outcome::TooLow,
+            _ => Outcome::Wrong,
+        };
+        Ok(Response {
+            outcome,
+            message: format!("(mock) your answer was {outcome}"),
+        })
+    }
+}
+
+/// Everything needed to send a day's answers
+pub struct Submission {
+    pub submitter: Box<dyn Submitter>,
+    pub ledger: Ledger,
+    /// Stars already in `answers/` count as solved, and newly accepted answers get
+    /// added there
+    pub accepted: Option<Expected>,
+}
+
+impl Submission {
+    /// The real thing
+    pub fn new(year: u16, day: usize) -> Result<Self, String> {
+        let submitter = HttpSubmitter::from_env()
+            .ok_or_else(|| format!("set ${} to submit answers", provider::SESSION_VAR))?;
+        Ok(Self {
+            submitter: Box::new(submitter),
+            ledger: Ledger::load(year)?,
+            accepted: Some(Expected::load(year, day)?),
+        })
+    }
+
+    /// Answers come from [`MockSubmitter::from_answers`] and nothing gets written down
+    pub fn dry_run(year: u16, day: usize) -> Result<Self, String> {
+        let ledger = Ledger::load(year)?;
+        Ok(Self {
+            submitter: Box::new(MockSubmitter::from_answers(year, day)?),
+            ledger: Ledger::in_memory(ledger.attempts().to_vec()),
+            accepted: None,
+        })
+    }
+
+    /// Submits each star in order, stopping at the first one that doesn't get
+    /// accepted since the second star can't be solved before the first.
+    /// Returns the outcome of each star that was actually sent
+    pub fn submit_day(
+        &mut self,
+        year: u16,
+        day: usize,
+        answers: [Option<Answer>; 2],
+    ) -> Vec<(usize, Outcome)> {
+        let mut sent = Vec::new();
+        for (star, answer) in (1..).zip(answers) {
+            let name = format!("Star {star}:");
+            let Some(answer) = answer else {
+                println!("{} {name} nothing to submit", "Skipping:".yellow());
+                break;
+            };
+            if answer.is_multiline() {
+                println!(
+                    "{} {name} the answer is a picture, read it and submit it by hand",
+                    "Skipping:".yellow()
+                );
+                break;
+            }
+            let answer = answer.to_string();
+
+            let solved = self
+                .accepted
+                .as_ref()
+                .and_then(|accepted| accepted.star(star == 2));
+            let check = match solved {
+                Some(solved) => Check::Solved(solved),
+                None => self.ledger.check(day, star, &answer),
+            };
+            match check {
+                Check::Submit => {}
+                Check::Solved(solved) if solved == answer => {
+                    let solved = display::fancy("✔", "Solved:");
+                    println!("{} {name} already solved with {answer}", solved.green());
+                    continue;
+                }
+                Check::Solved(solved) => {
+                    println!(
+                        "{} {name} already solved with {solved} but this got {answer}",
+                        "Warning:".yellow()
+                    );
+                    continue;
+                }
+                Check::AlreadyWrong(outcome) => {
+                    println!(
+                        "{} {name} {answer} was already submitted and was {outcome}",
+                        "Refusing:".red()
+                    );
+                    break;
+                }
+                Check::AboveBound(bound) => {
+                    println!(
+                        "{} {name} {answer} can't be right, {bound} was already too high",
+                        "Refusing:".red()
+                    );
+                    break;
+                }
+                Check::BelowBound(bound) => {
+                    println!(
+                        "{} {name} {answer} can't be right, {bound} was already too low",
+                        "Refusing:".red()
+                    );
+                    break;
+                }
+            }
+
+            let response = match self.submitter.submit(year, day, star, &answer) {
+                Ok(response) => response,
+                Err(e) => {
+                    println!("{} {name} could not submit {answer}: {e}", "Error:".red());
+                    break;
+                }
+            };
+            let outcome = response.outcome;
+            println!("{name} {answer} was {outcome}");
+            println!("{}", response.message.dimmed());
+            if let Err(e) = self
+                .ledger
+                .record(Attempt::new(day, star, &answer, outcome))
+            {
+                println!("{} could not record the attempt: {e}", "Warning:".yellow());
+            }
+            sent.push((star, outcome));
+
+            if outcome != Outcome::Correct {
+                break;
+            }
+            if self.accepted.is_some() {
+                if let Err(e) = Expected::save(year, day, star == 2, &answer) {
+                    println!("{} could not save the answer: {e}", "Warning:".yellow());
+                }
+            }
+        }
+        sent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOO_HIGH: &str = r#"<main>
+<article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href="/2023/about">about page</a>.  Please wait one minute before trying again. <a href="/2023/day/1">[Return to Day 1]</a></p></article>
+</main>"#;
+
+    const RATE_LIMITED: &str = r#"<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href="/2023/day/1">[Return to Day 1]</a></p></article>"#;
+
+    #[test]
+    fn parses_responses() {
+        let response = Response::parse(TOO_HIGH).unwrap();
+        assert_eq!(response.outcome, Outcome::TooHigh);
+        assert!(response.message.starts_with("That's not the right answer;"));
+        assert!(!response.message.contains('<'));
+
+        let response = Response::parse(RATE_LIMITED).unwrap();
+        assert_eq!(response.outcome, Outcome::RateLimited);
+        assert!(response.message.contains("You have 34s left to wait."));
+
+        assert!(Response::parse("<article><p>Something new</p></article>").is_none());
+    }
+
+    #[test]
+    fn ledger_refuses_known_wrong_answers() {
+        let mut ledger = Ledger::in_memory(Vec::new());
+        ledger
+            .record(Attempt::new(1, 1, "500", Outcome::TooHigh))
+            .unwrap();
+        ledger
+            .record(Attempt::new(1, 1, "100", Outcome::TooLow))
+            .unwrap();
+        ledger
+            .record(Attempt::new(1, 1, "250", Outcome::Wrong))
+            .unwrap();
+        ledger
+            .record(Attempt::new(1, 1, "300", Outcome::RateLimited))
+            .unwrap();
+
+        assert_eq!(
+            ledger.check(1, 1, "250"),
+            Check::AlreadyWrong(Outcome::Wrong)
+        );
+        assert_eq!(ledger.check(1, 1, "600"), Check::AboveBound("500".into()));
+        assert_eq!(
+            ledger.check(1, 1, "100"),
+            Check::AlreadyWrong(Outcome::TooLow)
+        );
+        assert_eq!(ledger.check(1, 1, "42"), Check::BelowBound("100".into()));
+        assert_eq!(ledger.check(1, 1, "300"), Check::Submit);
+        assert_eq!(ledger.check(1, 2, "600"), Check::Submit);
+    }
+
+    #[test]
+    fn stops_at_first_wrong_star() {
+        let mock = MockSubmitter::default()
+            .accept(2023, 1, 1, "142")
+            .accept(2023, 1, 2, "281");
+        let mut submission = Submission {
+            submitter: Box::new(mock),
+            ledger: Ledger::in_memory(Vec::new()),
+            accepted: None,
+        };
+
+        let sent = submission.submit_day(2023, 1, [Some(Answer::Int(142)), Some(Answer::Int(300))]);
+        assert_eq!(sent, [(1, Outcome::Correct), (2, Outcome::TooHigh)]);
+
+        // star 1 is solved now and star 2 can't go any higher
+        let sent = submission.submit_day(2023, 1, [Some(Answer::Int(142)), Some(Answer::Int(301))]);
+        assert!(sent.is_empty());
+
+        let sent = submission.submit_day(2023, 1, [Some(Answer::Int(142)), Some(Answer::Int(281))]);
+        assert_eq!(sent, [(2, Outcome::Correct)]);
+    }
+}
@@ -0,0 +1,171 @@
+//! Every answer we've submitted, one json line each in `answers/<year>/submissions.jsonl`.
+//!
+//! Advent of Code makes you wait longer after every wrong answer so it's worth
+//! remembering which ones were wrong and which way they were off.
+
+use std::{
+    fs::{
+        self,
+        OpenOptions,
+    },
+    io::{
+        self,
+        ErrorKind,
+        Write,
+    },
+    path::PathBuf,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::Outcome;
+
+pub const LEDGER_FILE: &str = "submissions.jsonl";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    pub timestamp: String,
+    pub day: usize,
+    pub star: usize,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    pub fn new(day: usize, star: usize, answer: &str, outcome: Outcome) -> Self {
+        Self {
+            timestamp: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            day,
+            star,
+            answer: answer.to_string(),
+            outcome,
+        }
+    }
+}
+
+/// What the ledger thinks of an answer before it gets sent
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing against it, go ahead
+    Submit,
+    /// The star was already solved with this answer
+    Solved(String),
+    /// Exactly this answer was already rejected
+    AlreadyWrong(Outcome),
+    /// At least this answer was already too high
+    AboveBound(String),
+    /// At most this answer was already too low
+    BelowBound(String),
+}
+
+/// A single year's attempts
+pub struct Ledger {
+    /// None keeps the attempts in memory only
+    path: Option<PathBuf>,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// A missing ledger just means nothing has been submitted yet
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = PathBuf::from("answers")
+            .join(year.to_string())
+            .join(LEDGER_FILE);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("Could not read {}: {e}", path.display())),
+        };
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|e| {
+                    format!(
+                        "Invalid attempt on line {} of {}: {e}",
+                        i + 1,
+                        path.display()
+                    )
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            path: Some(path),
+            attempts,
+        })
+    }
+
+    /// Checks against `attempts` but never writes anything down
+    pub fn in_memory(attempts: Vec<Attempt>) -> Self {
+        Self {
+            path: None,
+            attempts,
+        }
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    fn attempts_at(&self, day: usize, star: usize) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.star == star)
+    }
+
+    /// The answer that got accepted, if one has
+    pub fn solved(&self, day: usize, star: usize) -> Option<&str> {
+        self.attempts_at(day, star)
+            .find(|attempt| attempt.outcome == Outcome::Correct)
+            .map(|attempt| attempt.answer.as_str())
+    }
+
+    pub fn check(&self, day: usize, star: usize, answer: &str) -> Check {
+        if let Some(solved) = self.solved(day, star) {
+            return Check::Solved(solved.to_string());
+        }
+
+        let mut lowest_too_high: Option<(i128, &str)> = None;
+        let mut highest_too_low: Option<(i128, &str)> = None;
+        for attempt in self.attempts_at(day, star) {
+            if attempt.answer == answer && attempt.outcome.is_wrong() {
+                return Check::AlreadyWrong(attempt.outcome);
+            }
+            let Ok(n) = attempt.answer.parse::<i128>() else {
+                continue;
+            };
+            match attempt.outcome {
+                Outcome::TooHigh if lowest_too_high.is_none_or(|(high, _)| n < high) => {
+                    lowest_too_high = Some((n, &attempt.answer))
+                }
+                Outcome::TooLow if highest_too_low.is_none_or(|(low, _)| n > low) => {
+                    highest_too_low = Some((n, &attempt.answer))
+                }
+                _ => {}
+            }
+        }
+
+        let Ok(answer) = answer.parse::<i128>() else {
+            return Check::Submit;
+        };
+        match (lowest_too_high, highest_too_low) {
+            (Some((high, bound)), _) if answer >= high => Check::AboveBound(bound.to_string()),
+            (_, Some((low, bound))) if answer <= low => Check::BelowBound(bound.to_string()),
+            _ => Check::Submit,
+        }
+    }
+
+    pub fn record(&mut self, attempt: Attempt) -> io::Result<()> {
+        if let Some(path) = &self.path {
+            fs::create_dir_all(path.parent().unwrap())?;
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", serde_json::to_string(&attempt)?)?;
+        }
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
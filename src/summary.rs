@@ -0,0 +1,131 @@
+//! Results of running a batch of days and the table we print them as
+
+use std::{
+    fmt::Display,
+    time::Duration,
+};
+
+use colored::Colorize;
+
+use crate::{
+    answer::Answer,
+    days::SolveError,
+    display,
+};
+
+pub struct StarRun {
+    pub result: Result<Answer, SolveError>,
+    pub elapsed: Duration,
+}
+
+pub struct DayRun {
+    pub parse: Duration,
+    pub stars: [StarRun; 2],
+}
+
+impl DayRun {
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.stars.iter().map(|star| star.elapsed).sum::<Duration>()
+    }
+}
+
+pub struct DaySummary {
+    pub day: usize,
+    /// Err if we never got as far as running the stars
+    pub run: Result<DayRun, String>,
+}
+
+pub struct Summary(pub Vec<DaySummary>);
+
+const DAY_WIDTH: usize = 3;
+const STAR_WIDTH: usize = 4;
+const TIME_WIDTH: usize = 11;
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self.0.iter().flat_map(|day| match &day.run {
+            Ok(run) => run
+                .stars
+                .iter()
+                .enumerate()
+                .map(|(i, star)| {
+                    let parse = (i == 0).then_some(run.parse);
+                    let (answer, elapsed) = match &star.result {
+                        Ok(answer) => (one_line(answer).bright_white(), Some(star.elapsed)),
+                        // never ran so there's no time to show
+                        Err(SolveError::Unimplemented) => ("unimplemented".dimmed(), None),
+                        Err(e) => (e.to_string().red(), Some(star.elapsed)),
+                    };
+                    (day.day, Some(i + 1), answer, parse, elapsed)
+                })
+                .collect::<Vec<_>>(),
+            Err(e) => vec![(day.day, None, e.red(), None, None)],
+        });
+        let rows: Vec<_> = rows.collect();
+
+        let answer_width = rows
+            .iter()
+            .map(|(_, _, answer, ..)| answer.chars().count())
+            .chain(std::iter::once("Answer".len()))
+            .max()
+            .unwrap_or_default();
+        let line_width = DAY_WIDTH + STAR_WIDTH + answer_width + 2 * TIME_WIDTH + 8;
+
+        writeln!(
+            f,
+            "{:>DAY_WIDTH$}  {:>STAR_WIDTH$}  {:<answer_width$}  {:>TIME_WIDTH$}  {:>TIME_WIDTH$}",
+            "Day".green(),
+            "Star".green(),
+            "Answer".green(),
+            "Parse".green(),
+            "Time".green(),
+        )?;
+        writeln!(f, "{}", display::candy_cane(line_width))?;
+
+        for (day, star, answer, parse, elapsed) in rows {
+            let star = star.map(|s| s.to_string()).unwrap_or_default();
+            writeln!(
+                f,
+                "{:>DAY_WIDTH$}  {:>STAR_WIDTH$}  {:<answer_width$}  {:>TIME_WIDTH$}  {:>TIME_WIDTH$}",
+                day.to_string().green(),
+                star.bright_yellow(),
+                answer,
+                parse.map(colored_duration).unwrap_or_default(),
+                elapsed.map(colored_duration).unwrap_or_default(),
+            )?;
+        }
+
+        let total: Duration = self
+            .0
+            .iter()
+            .filter_map(|day| day.run.as_ref().ok())
+            .map(DayRun::elapsed)
+            .sum();
+        writeln!(f, "{}", display::candy_cane(line_width))?;
+        write!(
+            f,
+            "{:<width$}  {:>TIME_WIDTH$}",
+            "Total".green(),
+            colored_duration(total),
+            width = line_width - TIME_WIDTH - 2,
+        )
+    }
+}
+
+/// keeps the table one row per star
+fn one_line(answer: &Answer) -> String {
+    let answer = answer.to_string();
+    match answer.split_once('\n') {
+        Some((first, _)) => format!("{first} ..."),
+        None => answer,
+    }
+}
+
+fn colored_duration(dur: Duration) -> colored::ColoredString {
+    let formatted = format!("{dur:.2?}");
+    match dur.as_millis() {
+        0..=9 => formatted.green(),
+        10..=999 => formatted.yellow(),
+        _ => formatted.red(),
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    collections::Index,
+    parse::ParseError,
+};
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Direction {
     North,
@@ -17,29 +22,32 @@ impl Direction {
         }
     }
 
-    pub fn go(&self, index: (usize, usize)) -> (usize, usize) {
+    pub const ALL: [Direction; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// `(rows, cols)` moved by one step
+    fn diff(&self) -> (isize, isize) {
         use Direction::*;
-        let diff = match self {
+        match self {
             North => (-1, 0),
             South => (1, 0),
             West => (0, -1),
             East => (0, 1),
-        };
-        (
-            (index.0 as isize + diff.0) as usize,
-            (index.1 as isize + diff.1) as usize,
-        )
+        }
+    }
+
+    /// `None` going off the top or left edge. Use [`Grid::step`](crate::collections::Grid::step)
+    /// to stay inside the bottom and right too
+    pub fn try_go(&self, index: Index) -> Option<Index> {
+        let diff = self.diff();
+        Some((
+            index.0.checked_add_signed(diff.0)?,
+            index.1.checked_add_signed(diff.1)?,
+        ))
     }
 
     pub fn sgo(&self, index: SIndex) -> SIndex {
-        use Direction::*;
-        let diff = match self {
-            North => (-1, 0),
-            South => (1, 0),
-            West => (0, -1),
-            East => (0, 1),
-        };
-        ((index.0 + diff.0), (index.1 + diff.1))
+        let diff = self.diff();
+        ((index.0 + diff.0 as i64), (index.1 + diff.1 as i64))
     }
 
     pub fn right_dir(&self) -> Self {
@@ -63,14 +71,15 @@ impl Direction {
     }
 }
 
-impl From<char> for Direction {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
         match c {
-            'U' => Direction::North,
-            'L' => Direction::West,
-            'R' => Direction::East,
-            'D' => Direction::South,
-            _ => panic!("Invalid direction char: {c}"),
+            'U' => Ok(Direction::North),
+            'L' => Ok(Direction::West),
+            'R' => Ok(Direction::East),
+            'D' => Ok(Direction::South),
+            _ => Err(ParseError::new(format!("Invalid direction char: {c}"))),
         }
     }
 }
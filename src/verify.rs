@@ -0,0 +1,234 @@
+//! Checking what the stars produce against answers Advent of Code already accepted
+//!
+//! Accepted answers live in `answers/<year>/dayNN.toml`:
+//!
+//! ```toml
+//! star1 = 56049
+//! star2 = "EZPCBRPJ"
+//! ```
+//!
+//! Either star can be left out if we don't know its answer yet.
+
+use std::{
+    env,
+    fmt::Display,
+    fs::{
+        self,
+        OpenOptions,
+    },
+    io::{
+        self,
+        ErrorKind,
+        Write,
+    },
+    path::PathBuf,
+};
+
+use colored::Colorize;
+use itertools::{
+    EitherOrBoth,
+    Itertools,
+};
+use serde::Deserialize;
+
+use crate::{
+    answer::Answer,
+    days::SolveError,
+    display,
+};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    star1: Option<ExpectedAnswer>,
+    star2: Option<ExpectedAnswer>,
+}
+
+/// toml makes us pick between a number and a string so accept both
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ExpectedAnswer {
+    Int(i64),
+    Str(String),
+}
+
+impl Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(i) => write!(f, "{i}"),
+            Self::Str(s) => write!(f, "{}", s.trim_end()),
+        }
+    }
+}
+
+impl Expected {
+    /// The accepted answers for a day. A day without an answers file has none
+    pub fn load(year: u16, day_num: usize) -> Result<Self, String> {
+        let filename = answers_file(year, day_num);
+        match fs::read_to_string(&filename) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|e| format!("Invalid {}: {}", filename.display(), e.message())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read {}: {e}", filename.display())),
+        }
+    }
+
+    pub fn star(&self, is_second_star: bool) -> Option<String> {
+        match is_second_star {
+            false => self.star1.as_ref(),
+            true => self.star2.as_ref(),
+        }
+        .map(ToString::to_string)
+    }
+
+    /// Write down an answer Advent of Code just accepted. Numbers are saved as
+    /// numbers and anything else as a string
+    pub fn save(year: u16, day_num: usize, is_second_star: bool, answer: &str) -> io::Result<()> {
+        let filename = answers_file(year, day_num);
+        fs::create_dir_all(filename.parent().unwrap())?;
+        let value = match answer.parse::<i64>() {
+            Ok(i) => toml::Value::Integer(i),
+            Err(_) => toml::Value::String(answer.to_string()),
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(filename)?;
+        writeln!(file, "star{} = {value}", is_second_star as usize + 1)
+    }
+}
+
+fn answers_file(year: u16, day_num: usize) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("answers")
+        .join(year.to_string())
+        .join(format!("day{:02}.toml", day_num))
+}
+
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Result<Answer, SolveError>,
+    },
+    /// Nothing to compare against so we didn't bother running it
+    Unverified,
+}
+
+impl Verdict {
+    pub fn check(expected: String, actual: Result<Answer, SolveError>) -> Self {
+        match &actual {
+            Ok(answer) if answer.to_string() == expected => Self::Pass,
+            _ => Self::Fail { expected, actual },
+        }
+    }
+}
+
+/// How many stars ended up with each [`Verdict`]
+#[derive(Default)]
+pub struct Tally {
+    pub passed: usize,
+    pub failed: usize,
+    pub unverified: usize,
+}
+
+impl Tally {
+    pub fn add(&mut self, verdict: &Verdict) {
+        match verdict {
+            Verdict::Pass => self.passed += 1,
+            Verdict::Fail { .. } => self.failed += 1,
+            Verdict::Unverified => self.unverified += 1,
+        }
+    }
+}
+
+impl Display for Tally {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} unverified",
+            self.passed.to_string().green(),
+            self.failed.to_string().red(),
+            self.unverified.to_string().yellow()
+        )
+    }
+}
+
+/// One star's verdict as printed by `--verify`
+pub struct StarVerdict {
+    pub day: usize,
+    pub star: usize,
+    pub verdict: Verdict,
+}
+
+impl Display for StarVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = format!("Day {:>2} star {}", self.day, self.star);
+        match &self.verdict {
+            Verdict::Pass => write!(f, "{} {name}", display::fancy("✔", "PASS").green()),
+            Verdict::Unverified => {
+                write!(
+                    f,
+                    "{} {name} {}",
+                    display::fancy("?", "????").yellow(),
+                    "no accepted answer".yellow()
+                )
+            }
+            Verdict::Fail { expected, actual } => {
+                writeln!(f, "{} {name}", display::fancy("✘", "FAIL").red())?;
+                match actual {
+                    Ok(answer) => write!(f, "{}", diff(expected, &answer.to_string())),
+                    Err(e) => write!(
+                        f,
+                        "    {}\n    {}",
+                        format!("- {expected}").red(),
+                        format!("+ Error: {e}").green()
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// What the tests generated by `macros::example_tests!` boil down to
+#[cfg(test)]
+pub fn assert_example(
+    day_num: usize,
+    day: &dyn crate::days::Solver,
+    input: &str,
+    is_second_star: bool,
+    expected: &str,
+) {
+    let puzzle = day
+        .parse(input)
+        .unwrap_or_else(|e| panic!("Failed to parse example: {e}"));
+    let actual = match is_second_star {
+        false => puzzle.star1(),
+        true => puzzle.star2(),
+    };
+    let verdict = Verdict::check(expected.to_string(), actual);
+    if matches!(verdict, Verdict::Fail { .. }) {
+        let star_verdict = StarVerdict {
+            day: day_num,
+            star: is_second_star as usize + 1,
+            verdict,
+        };
+        panic!("\n{star_verdict}");
+    }
+}
+
+/// Line by line so pictures only flag the rows that changed
+fn diff(expected: &str, actual: &str) -> String {
+    expected
+        .lines()
+        .zip_longest(actual.lines())
+        .flat_map(|lines| match lines {
+            EitherOrBoth::Both(e, a) if e == a => vec![format!("  {e}").dimmed()],
+            EitherOrBoth::Both(e, a) => vec![format!("- {e}").red(), format!("+ {a}").green()],
+            EitherOrBoth::Left(e) => vec![format!("- {e}").red()],
+            EitherOrBoth::Right(a) => vec![format!("+ {a}").green()],
+        })
+        .map(|line| format!("    {line}"))
+        .join("\n")
+}
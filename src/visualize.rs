@@ -0,0 +1,123 @@
+//! Watching a star get worked out with `--visualize`
+//!
+//! Days draw frames through [`Day::visualize`](crate::days::Day::visualize) and
+//! [`Frames`] either animates them in the terminal or saves them for `aoc play`.
+//! Saved frames are separated by a line with just a form feed on it.
+
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{
+        self,
+        BufWriter,
+        Write,
+    },
+    path::Path,
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+const SEPARATOR: &str = "\x0c";
+
+/// Moves the cursor home and clears the screen
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+enum Sink {
+    Terminal {
+        delay: Duration,
+        last: Option<Instant>,
+    },
+    File(BufWriter<File>),
+}
+
+/// Where frames go
+pub struct Frames {
+    sink: Sink,
+    count: usize,
+}
+
+impl Frames {
+    /// Draws each frame over the last one, at most `fps` a second
+    pub fn terminal(fps: f64) -> Self {
+        Self {
+            sink: Sink::Terminal {
+                delay: Duration::from_secs_f64(1. / fps),
+                last: None,
+            },
+            count: 0,
+        }
+    }
+
+    /// Saves frames to `path` to be played later, replacing whatever was there
+    pub fn file(path: &Path) -> io::Result<Self> {
+        Ok(Self {
+            sink: Sink::File(BufWriter::new(File::create(path)?)),
+            count: 0,
+        })
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn frame(&mut self, frame: impl Display) {
+        self.count += 1;
+        // a broken terminal or full disk shouldn't take the star down with it
+        let _ = match &mut self.sink {
+            Sink::Terminal { delay, last } => {
+                if let Some(remaining) = last.and_then(|last| delay.checked_sub(last.elapsed())) {
+                    thread::sleep(remaining);
+                }
+                *last = Some(Instant::now());
+                let mut stdout = io::stdout().lock();
+                writeln!(stdout, "{CLEAR}{frame}").and_then(|_| stdout.flush())
+            }
+            Sink::File(file) => write!(file, "{frame}\n{SEPARATOR}\n"),
+        };
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        match self.sink {
+            Sink::Terminal { .. } => Ok(()),
+            Sink::File(mut file) => file.flush(),
+        }
+    }
+}
+
+/// Plays frames saved with `--frames-to` back in the terminal
+pub fn play(path: &Path, fps: f64) -> io::Result<()> {
+    let saved = std::fs::read_to_string(path)?;
+    let mut frames = Frames::terminal(fps);
+    for frame in saved.split(&format!("\n{SEPARATOR}\n")) {
+        if !frame.is_empty() {
+            frames.frame(frame);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_survive_a_round_trip() {
+        let path = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = Frames::file(&path).unwrap();
+        frames.frame("#.\n.#");
+        frames.frame("##\n##");
+        assert_eq!(frames.count(), 2);
+        frames.finish().unwrap();
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        let split: Vec<_> = saved
+            .split(&format!("\n{SEPARATOR}\n"))
+            .filter(|frame| !frame.is_empty())
+            .collect();
+        assert_eq!(split, ["#.\n.#", "##\n##"]);
+        std::fs::remove_file(path).unwrap();
+    }
+}
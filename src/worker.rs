@@ -0,0 +1,273 @@
+//! Running each day on its own thread so a panic or a runaway search only
+//! takes out that step instead of the whole run
+//!
+//! The worker parses the input once and runs the stars it was asked for against
+//! it. There's no
+//! way to stop a thread from the outside so a step that times out keeps going in
+//! the background until we exit. Anything timed after it will be sharing the CPU
+//! with it.
+
+use std::{
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+    sync::mpsc::{
+        self,
+        Receiver,
+        RecvTimeoutError,
+        Sender,
+    },
+    thread,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::{
+    days::{
+        SolveError,
+        Solver,
+    },
+    summary::StarRun,
+};
+
+/// What the worker has to say, in the order it says it
+enum Report {
+    Parsed(Result<Duration, SolveError>),
+    Star(StarRun),
+}
+
+/// A day being worked out on a thread of its own. Each step gets `timeout`,
+/// counted from when the step before it reported back or the thread was spawned
+pub struct DayWorker {
+    day: &'static dyn Solver,
+    input: &'static str,
+    timeout: Option<Duration>,
+    reports: Receiver<Report>,
+    /// when the step being waited on started, as far as we can tell from here
+    since: Instant,
+    /// which of star 1 and star 2 the worker runs
+    stars: [bool; 2],
+    next_is_second_star: bool,
+}
+
+impl DayWorker {
+    /// Starts parsing `input` then runs star 1 and star 2 against it
+    pub fn spawn(day: &'static dyn Solver, input: &'static str, timeout: Option<Duration>) -> Self {
+        Self::spawn_stars(day, input, [true, true], timeout)
+    }
+
+    /// Like [`Self::spawn`] but only runs the stars set in `stars`, the rest never start
+    pub fn spawn_stars(
+        day: &'static dyn Solver,
+        input: &'static str,
+        stars: [bool; 2],
+        timeout: Option<Duration>,
+    ) -> Self {
+        let (tx, reports) = mpsc::channel();
+        thread::spawn(move || work(day, input, stars, tx));
+        Self {
+            day,
+            input,
+            timeout,
+            reports,
+            since: Instant::now(),
+            stars,
+            next_is_second_star: !stars[0],
+        }
+    }
+
+    /// How long parsing took, or why there's nothing to run the stars against
+    pub fn parsed(&mut self) -> Result<Duration, SolveError> {
+        match self.wait()? {
+            Report::Parsed(parsed) => parsed,
+            Report::Star(_) => unreachable!("the worker parses before running anything"),
+        }
+    }
+
+    /// The next star asked for, star 1 first. Only call once [`Self::parsed`] is fine
+    pub fn next_star(&mut self) -> StarRun {
+        let is_second_star = self.next_is_second_star;
+        self.next_is_second_star = true;
+        let since = self.since;
+        let result = match self.wait() {
+            Ok(Report::Star(run)) => return run,
+            // only from a worker that took over for star 2, see below
+            Ok(Report::Parsed(Ok(_))) => return self.next_star(),
+            Ok(Report::Parsed(Err(e))) | Err(e) => Err(e),
+        };
+        if matches!(result, Err(SolveError::TimedOut(_))) && !is_second_star && self.stars[1] {
+            // still stuck on star 1 so star 2 needs a thread of its own
+            *self = Self::spawn_stars(self.day, self.input, [false, true], self.timeout);
+        }
+        StarRun {
+            result,
+            elapsed: since.elapsed(),
+        }
+    }
+
+    fn wait(&mut self) -> Result<Report, SolveError> {
+        let report = match self.timeout {
+            Some(timeout) => self
+                .reports
+                .recv_timeout(timeout.saturating_sub(self.since.elapsed())),
+            None => self
+                .reports
+                .recv()
+                .map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        self.since = Instant::now();
+        report.map_err(|e| match e {
+            RecvTimeoutError::Timeout => SolveError::TimedOut(self.timeout.unwrap()),
+            // everything the day does is behind `catch_unwind` so this shouldn't happen
+            RecvTimeoutError::Disconnected => {
+                SolveError::Panicked("the worker stopped without a word".to_string())
+            }
+        })
+    }
+}
+
+/// Everything that happens on the worker thread
+fn work(day: &'static dyn Solver, input: &'static str, stars: [bool; 2], reports: Sender<Report>) {
+    let start = Instant::now();
+    let puzzle = match catch(|| Ok(day.parse(input)?)) {
+        Ok(puzzle) => {
+            let _ = reports.send(Report::Parsed(Ok(start.elapsed())));
+            puzzle
+        }
+        Err(e) => {
+            let _ = reports.send(Report::Parsed(Err(e)));
+            return;
+        }
+    };
+
+    for is_second_star in [false, true].into_iter().filter(|s| stars[*s as usize]) {
+        let run = match day.implemented(is_second_star) {
+            false => StarRun {
+                result: Err(SolveError::Unimplemented),
+                elapsed: Duration::ZERO,
+            },
+            true => {
+                let start = Instant::now();
+                let result = catch(|| match is_second_star {
+                    false => puzzle.star1(),
+                    true => puzzle.star2(),
+                });
+                StarRun {
+                    result,
+                    elapsed: start.elapsed(),
+                }
+            }
+        };
+        if reports.send(Report::Star(run)).is_err() {
+            // nobody is listening anymore
+            return;
+        }
+    }
+}
+
+/// Runs `f` turning a panic into [`SolveError::Panicked`]
+fn catch<T>(f: impl FnOnce() -> Result<T, SolveError>) -> Result<T, SolveError> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(SolveError::Panicked(panic_message(payload))))
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(msg) => msg.to_string(),
+            Err(_) => "no message".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        days::Day,
+        parse::ParseError,
+    };
+
+    /// Does whatever its input says
+    struct Misbehaves;
+
+    impl Day for Misbehaves {
+        type Input<'a> = &'a str;
+        type Answer = usize;
+        const STARS: [bool; 2] = [true, true];
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, ParseError> {
+            match input {
+                "parse panics" => panic!("bad input: {input}"),
+                "parse hangs" => hang(),
+                _ => Ok(input),
+            }
+        }
+
+        fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+            match *input {
+                "star 1 panics" => panic!("bad input: {input}"),
+                "star 1 hangs" => hang(),
+                _ => Ok(1),
+            }
+        }
+
+        fn star2(&self, _input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+            Ok(2)
+        }
+    }
+
+    fn hang() -> ! {
+        loop {
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn answer(run: &StarRun) -> Option<String> {
+        run.result.as_ref().ok().map(ToString::to_string)
+    }
+
+    #[test]
+    fn catches_panics() {
+        let mut worker = DayWorker::spawn(&Misbehaves, "parse panics", None);
+        assert!(
+            matches!(worker.parsed(), Err(SolveError::Panicked(msg)) if msg == "bad input: parse panics")
+        );
+
+        let mut worker = DayWorker::spawn(&Misbehaves, "star 1 panics", None);
+        assert!(worker.parsed().is_ok());
+        let star1 = worker.next_star();
+        assert!(
+            matches!(star1.result, Err(SolveError::Panicked(msg)) if msg == "bad input: star 1 panics")
+        );
+        // the same parsed input carries on to star 2
+        assert_eq!(answer(&worker.next_star()).as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn times_out() {
+        let timeout = Duration::from_millis(50);
+        let mut worker = DayWorker::spawn(&Misbehaves, "parse hangs", Some(timeout));
+        assert!(matches!(worker.parsed(), Err(SolveError::TimedOut(after)) if after == timeout));
+
+        let mut worker = DayWorker::spawn(&Misbehaves, "star 1 hangs", Some(timeout));
+        assert!(worker.parsed().is_ok());
+        let star1 = worker.next_star();
+        assert!(matches!(star1.result, Err(SolveError::TimedOut(after)) if after == timeout));
+        assert!(star1.elapsed >= timeout);
+        // star 1 is still going so star 2 gets a fresh worker
+        assert_eq!(answer(&worker.next_star()).as_deref(), Some("2"));
+    }
+
+    #[test]
+    fn skips_stars_nobody_asked_for() {
+        // star 1 would hang forever with no timeout
+        let mut worker = DayWorker::spawn_stars(&Misbehaves, "star 1 hangs", [false, true], None);
+        assert!(worker.parsed().is_ok());
+        assert_eq!(answer(&worker.next_star()).as_deref(), Some("2"));
+    }
+}
@@ -0,0 +1,12 @@
+//! Every season's solutions, one module per year. The `pub mod` lines here and in
+//! each year are the list of days everything else is built from. `aoc scaffold`
+//! declares new ones and `macros::check_years!` makes sure none were missed
+
+pub mod y2023;
+
+macros::check_years!();
+
+#[cfg(test)]
+mod examples {
+    macros::example_tests!();
+}
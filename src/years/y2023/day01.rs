@@ -5,18 +5,19 @@ use crate::prelude::*;
 pub struct Day01;
 
 impl Day for Day01 {
-    fn star1(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .sum_by(|val: CalibrationValue| val.0)
-            .to_string()
+    type Input<'a> = &'a str;
+    type Answer = u32;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.parsed_lines().sum_by(|val: CalibrationValue| val.0))
     }
 
-    fn star2(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .sum_by(|val: FancyCalibrationValue| val.0)
-            .to_string()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .try_parsed_lines()
+            .map_ok(|val: FancyCalibrationValue| val.0)
+            .sum::<Result<_, _>>()?)
     }
 }
 
@@ -52,9 +53,12 @@ lazy_static! {
 struct FancyCalibrationValue(u32);
 
 impl FromStr for FancyCalibrationValue {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let first = parse_maybe_written_number(NUMBERS_REGEX.find(s).unwrap().as_str());
+        let first = NUMBERS_REGEX
+            .find(s)
+            .map(|m| parse_maybe_written_number(m.as_str()))
+            .ok_or_else(|| ParseError::at(s, "line has no digits or written numbers"))?;
         let last = SREBMUN_REGEX
             .find(&s.chars().rev().collect::<String>())
             .map(|m| parse_maybe_written_number(&m.as_str().chars().rev().collect::<String>()))
@@ -2,25 +2,25 @@ use crate::prelude::*;
 
 pub struct Day02;
 impl Day for Day02 {
-    fn star1(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .filter(|game: &Game| {
+    type Input<'a> = Vec<Game>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .iter()
+            .filter(|game| {
                 game.subsets.iter().all(|subset| {
                     subset.red <= RED_LIMIT
                         && subset.green <= GREEN_LIMIT
                         && subset.blue <= BLUE_LIMIT
                 })
             })
-            .sum_by(|game| game.id)
-            .to_string()
+            .sum_by(|game| game.id))
     }
 
-    fn star2(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .sum_by(|game: Game| game.power())
-            .to_string()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.iter().sum_by(|game| game.power()))
     }
 }
 
@@ -28,19 +28,19 @@ const RED_LIMIT: usize = 12;
 const GREEN_LIMIT: usize = 13;
 const BLUE_LIMIT: usize = 14;
 
-struct Game {
+pub struct Game {
     id: usize,
     subsets: Vec<Subset>,
 }
 
 impl FromStr for Game {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (game_header, subsets) = s.split_once(": ").unwrap();
+        let (game_header, subsets) = s.try_split_once(": ")?;
 
-        let id = game_header[5..].parse().unwrap();
+        let id = game_header.try_strip_prefix("Game ")?.try_parse()?;
 
-        let subsets = subsets.split("; ").parse_each().collect();
+        let subsets = subsets.split("; ").try_parse_each().try_collect()?;
 
         Ok(Self { id, subsets })
     }
@@ -81,17 +81,22 @@ impl Subset {
 }
 
 impl FromStr for Subset {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut subset = Subset::empty();
         for handful in s.split(", ") {
-            let (num, color) = handful.split_once(' ').unwrap();
-            let num = num.parse().unwrap();
+            let (num, color) = handful.try_split_once(" ")?;
+            let num = num.try_parse()?;
             match color {
                 "red" => subset.red = num,
                 "green" => subset.green = num,
                 "blue" => subset.blue = num,
-                _ => panic!("Tried to parse color but received: {color}"),
+                _ => {
+                    return Err(ParseError::at(
+                        color,
+                        format!("Tried to parse color but received: {color}"),
+                    ))
+                }
             }
         }
         Ok(subset)
@@ -0,0 +1,113 @@
+use crate::prelude::*;
+
+pub struct Day03;
+impl Day for Day03 {
+    type Input<'a> = Engine;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        let mut total_parts: usize = 0;
+        let mut engine = input.clone();
+        for index in input.raw_data.indices() {
+            if engine.has_symbol_at(index) {
+                let parts = engine.scan_for_parts(index);
+                total_parts += parts.iter().sum::<usize>();
+            }
+        }
+        Ok(total_parts)
+    }
+
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        let mut total_ratio: usize = 0;
+        let mut engine = input.clone();
+        for index in input.raw_data.indices() {
+            if engine.raw_data[index] == '*' {
+                total_ratio += engine.gear_ratio(index);
+            }
+        }
+        Ok(total_ratio)
+    }
+}
+
+#[derive(Clone)]
+pub struct Engine {
+    raw_data: Grid<char>,
+    found_parts: Grid<bool>,
+}
+
+impl Parse<'_> for Engine {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let raw_data: Grid<char> = s.parse()?;
+        let found_parts = raw_data.map(|_| false);
+        Ok(Self {
+            raw_data,
+            found_parts,
+        })
+    }
+}
+
+impl Engine {
+    /// Takes indicies pointing to a digit of a part.
+    /// returns the whole part.
+    ///
+    /// If the part was previously returned then this will return None
+    fn get_parts(&mut self, (i, j): Index) -> Option<usize> {
+        if self.found_parts[(i, j)] {
+            return None;
+        }
+        let row = self.raw_data.rows().nth(i).unwrap();
+        let mut left_index = j;
+        while let Some(true) = left_index
+            .checked_sub(1)
+            .and_then(|left| row.get(left))
+            .map(|c| c.is_ascii_digit())
+        {
+            left_index -= 1
+        }
+        let mut right_index = j;
+        while let Some(true) = row.get(right_index + 1).map(|c| c.is_ascii_digit()) {
+            right_index += 1
+        }
+        let part = row[left_index..=right_index]
+            .iter()
+            .join("")
+            .parse()
+            .unwrap();
+
+        for j in left_index..=right_index {
+            *self.found_parts.get_mut((i, j)).unwrap() = true;
+        }
+
+        Some(part)
+    }
+
+    fn scan_for_parts(&mut self, index: Index) -> Vec<usize> {
+        let mut found_parts = Vec::new();
+        for neighbor in self.raw_data.neighbors8(index) {
+            if self.raw_data[neighbor].is_ascii_digit() {
+                if let Some(part) = self.get_parts(neighbor) {
+                    found_parts.push(part)
+                }
+            }
+        }
+        found_parts
+    }
+
+    fn has_symbol_at(&self, index: Index) -> bool {
+        let c = self.raw_data[index];
+        !c.is_ascii_digit() && c != '.'
+    }
+
+    fn gear_ratio(&mut self, index: Index) -> usize {
+        // kinda horrible but :shrug:
+        (&mut self.found_parts)
+            .into_iter()
+            .for_each(|row| row.fill(false));
+        let parts = self.scan_for_parts(index);
+        match parts.len() {
+            2 => parts[0] * parts[1],
+            _ => 0,
+        }
+    }
+}
@@ -2,19 +2,20 @@ use crate::prelude::*;
 
 pub struct Day04;
 impl Day for Day04 {
-    fn star1(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .sum_by(|card: Card| card.score())
-            .to_string()
+    type Input<'a> = Vec<Card>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.iter().sum_by(|card| card.score()))
     }
 
-    fn star2(&self, input: String) -> String {
-        input.parse::<CardSheet>().unwrap().score().to_string()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(CardSheet::new(input).score())
     }
 }
 
-struct Card {
+pub struct Card {
     numbers: Vec<usize>,
     // I started with HashSet here for constant time lookup but it turns out vec is faster
     // I guess since the vec is small the constant time factors on hashing are too big.
@@ -31,12 +32,18 @@ struct Card {
 }
 
 impl FromStr for Card {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, nums) = s.split_once(": ").unwrap();
-        let (winning, numbers) = nums.split_once(" | ").unwrap();
-        let numbers = numbers.split_ascii_whitespace().parse_each().collect();
-        let winning_numbers = winning.split_ascii_whitespace().parse_each().collect();
+        let (_, nums) = s.try_split_once(": ")?;
+        let (winning, numbers) = nums.try_split_once(" | ")?;
+        let numbers = numbers
+            .split_ascii_whitespace()
+            .try_parse_each()
+            .try_collect()?;
+        let winning_numbers = winning
+            .split_ascii_whitespace()
+            .try_parse_each()
+            .try_collect()?;
         Ok(Self {
             winning_numbers,
             numbers,
@@ -60,25 +67,21 @@ impl Card {
     }
 }
 
-struct CardSheet {
-    cards: Vec<Card>,
+struct CardSheet<'a> {
+    cards: &'a [Card],
     // lmao adding this takes the runtime from 30s to 2ms
     // we love a little memoizatoin
     card_scores: Vec<Option<usize>>,
 }
 
-impl FromStr for CardSheet {
-    type Err = Infallible;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let cards = s.parsed_lines().collect_vec();
-        Ok(Self {
+impl<'a> CardSheet<'a> {
+    fn new(cards: &'a [Card]) -> Self {
+        Self {
             card_scores: vec![None; cards.len()],
             cards,
-        })
+        }
     }
-}
 
-impl CardSheet {
     fn score(&mut self) -> usize {
         (0..self.cards.len())
             .map(|i| self.tally_scratch_card(i))
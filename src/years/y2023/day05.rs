@@ -11,34 +11,34 @@ use rayon::prelude::{
 
 pub struct Day05;
 impl Day for Day05 {
-    fn star1(&self, input: String) -> String {
-        let almanac = Almanac::parse(&input);
+    type Input<'a> = Almanac<'a>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, almanac: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
         almanac
             .starting_seeds
             .iter()
             .map(|seed| almanac.get_seed_location(*seed))
             .min()
-            .unwrap()
-            .to_string()
+            .or_no_answer("no seeds to plant")
     }
 
-    fn star2(&self, input: String) -> String {
-        let almanac = Almanac::parse(&input);
+    fn star2(&self, almanac: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
         almanac
             .starting_seeds
             .iter()
             .tuples::<(_, _)>()
             // sometimes the right answer is to just throw more compute at the problem
             .par_bridge()
-            .flat_map(|(start, len)| (*start..*start + len))
+            .flat_map(|(start, len)| *start..*start + len)
             .map(|seed| almanac.get_seed_location(seed))
             .min()
-            .unwrap()
-            .to_string()
+            .or_no_answer("no seeds to plant")
     }
 }
 
-struct Almanac<'a> {
+pub struct Almanac<'a> {
     starting_seeds: Vec<usize>,
     maps: HashMap<&'a str, Map<'a>>,
 }
@@ -80,22 +80,34 @@ impl<'a> Almanac<'a> {
         self.get_range_permutations("seed", range)
     }
     */
+}
 
-    fn parse(s: &'a str) -> Self {
+impl<'a> Parse<'a> for Almanac<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
         let mut sections = s.split("\n\n");
         let starting_seeds = sections
             .next()
-            .unwrap()
-            .strip_prefix("seeds: ")
-            .unwrap()
+            .unwrap_or_default()
+            .try_strip_prefix("seeds: ")?
             .split_ascii_whitespace()
-            .parse_each()
-            .collect();
-        let maps = sections.map(Map::parse).collect();
-        Self {
+            .try_parse_each()
+            .try_collect()?;
+        let maps: HashMap<_, _> = sections.map(Map::parse).try_collect()?;
+
+        // make sure every chain of maps actually ends up at a location
+        if !maps.contains_key("seed") {
+            return Err(ParseError::new("no map from seed"));
+        }
+        for map in maps.values() {
+            if map.to != "location" && !maps.contains_key(map.to) {
+                return Err(ParseError::at(map.to, format!("no map from {}", map.to)));
+            }
+        }
+
+        Ok(Self {
             maps,
             starting_seeds,
-        }
+        })
     }
 }
 /*fn get_range_subsections<'a>(
@@ -182,7 +194,7 @@ impl<'a> Map<'a> {
             .unwrap_or(num)
     }
 
-    /// this only works if you have already made this a continuous range. See [`get_range_subsections`]
+    // this only works if you have already made this a continuous range. See [`get_range_subsections`]
     // fn convert_range(&self, range: Range<usize>) -> Range<usize> {
     //     self.convert(range.start)..(self.convert(range.end-1)+1)
     // }
@@ -200,21 +212,18 @@ impl<'a> Map<'a> {
 
     /// FromStr does not allow us to use lifetime of str
     /// Also want to return the from type here
-    fn parse(s: &'a str) -> (&'a str, Self) {
+    fn parse(s: &'a str) -> Result<(&'a str, Self), ParseError> {
         let mut lines_iter = s.lines();
         let (from, to) = lines_iter
             .next()
-            .unwrap()
-            .split_ascii_whitespace()
-            .next()
-            .unwrap()
-            .split_once("-to-")
-            .unwrap();
+            .unwrap_or_default()
+            .try_strip_suffix(" map:")?
+            .try_split_once("-to-")?;
         let map = Self {
             to,
-            ranges: lines_iter.parse_each().collect(),
+            ranges: lines_iter.try_parse_each().try_collect()?,
         };
-        (from, map)
+        Ok((from, map))
     }
 }
 
@@ -251,12 +260,16 @@ impl Conversion {
 }
 
 impl FromStr for Conversion {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split_ascii_whitespace();
-        let dest_start: usize = split.next().unwrap().parse().unwrap();
-        let src_start: usize = split.next().unwrap().parse().unwrap();
-        let range_len: usize = split.next().unwrap().parse().unwrap();
+        let Some((dest_start, src_start, range_len)) = s
+            .split_ascii_whitespace()
+            .try_parse_each::<usize>()
+            .collect_tuple()
+        else {
+            return Err(ParseError::at(s, "expected three numbers"));
+        };
+        let (dest_start, src_start, range_len) = (dest_start?, src_start?, range_len?);
         let range = src_start..src_start + range_len;
         Ok(Self { range, dest_start })
     }
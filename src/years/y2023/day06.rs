@@ -0,0 +1,107 @@
+use crate::prelude::*;
+
+pub struct Day06;
+impl Day for Day06 {
+    type Input<'a> = RaceSheet;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .races
+            .iter()
+            .map(|race| race.get_num_winning_times())
+            .product())
+    }
+
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.kerned_race.get_num_winning_times())
+    }
+}
+
+/// The sheet can be read as several races or, ignoring the spaces, one big race
+pub struct RaceSheet {
+    races: Vec<Race>,
+    kerned_race: Race,
+}
+
+impl Parse<'_> for RaceSheet {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            races: parse_races(s)?,
+            kerned_race: s.parse()?,
+        })
+    }
+}
+
+struct Race {
+    time: usize,
+    record: usize,
+}
+
+impl Race {
+    fn get_num_winning_times(&self) -> usize {
+        let (lower_bound, upper_bound) = get_times(self.time, self.record);
+        // tying the record doesn't win so step past exact roots
+        let lower = lower_bound.floor() as usize + 1;
+        let upper = upper_bound.ceil() as usize - 1;
+        upper - lower + 1
+    }
+}
+
+impl FromStr for Race {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let time = lines
+            .next()
+            .unwrap_or_default()
+            .try_strip_prefix("Time:")?
+            .split_ascii_whitespace()
+            .join("")
+            .try_parse()?;
+        let record = lines
+            .next()
+            .unwrap_or_default()
+            .try_strip_prefix("Distance:")?
+            .split_ascii_whitespace()
+            .join("")
+            .try_parse()?;
+        Ok(Self { time, record })
+    }
+}
+
+fn parse_races(s: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = s.lines();
+    let times = lines
+        .next()
+        .unwrap_or_default()
+        .try_strip_prefix("Time:")?
+        .split_ascii_whitespace()
+        .try_parse_each();
+    let distances = lines
+        .next()
+        .unwrap_or_default()
+        .try_strip_prefix("Distance:")?
+        .split_ascii_whitespace()
+        .try_parse_each();
+    times
+        .zip(distances)
+        .map(|(time, record)| {
+            Ok(Race {
+                time: time?,
+                record: record?,
+            })
+        })
+        .collect()
+}
+
+/// Takes in race time and record. Returns the 2 possible times that could have set that record
+fn get_times(time: usize, record: usize) -> (f64, f64) {
+    // and who said we would never use quadtratic formula in the real world
+    let time = time as f64;
+    let record = record as f64;
+    let half_time = time / 2.;
+    let quadrat = f64::sqrt(time.powi(2) - 4. * record) / 2.;
+    (half_time - quadrat, half_time + quadrat)
+}
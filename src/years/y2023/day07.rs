@@ -3,39 +3,42 @@ use std::cmp::Ordering;
 
 pub struct Day07;
 impl Day for Day07 {
-    fn star1(&self, input: String) -> String {
-        input
-            .parsed_lines::<Hand>()
+    type Input<'a> = Vec<Hand>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .iter()
             .sorted()
             .enumerate()
-            .sum_by(|(score, hand)| (score + 1) * hand.bid)
-            .to_string()
+            .sum_by(|(score, hand)| (score + 1) * hand.bid))
     }
 
-    fn star2(&self, input: String) -> String {
-        input
-            .parsed_lines::<Hand>()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .iter()
+            .cloned()
             .map(|hand| hand.switch_to_wild_type())
             .sorted()
             .enumerate()
-            .sum_by(|(score, hand)| (score + 1) * hand.bid)
-            .to_string()
+            .sum_by(|(score, hand)| (score + 1) * hand.bid))
     }
 }
 
-#[derive(PartialEq, Eq)]
-struct Hand {
+#[derive(PartialEq, Eq, Clone)]
+pub struct Hand {
     cards: Vec<Card>,
     bid: usize,
     hand_type: HandType,
 }
 
 impl FromStr for Hand {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (cards, bid) = s.split_once(' ').unwrap();
-        let cards = cards.chars().map(Card::from).collect_vec();
-        let bid = bid.parse().unwrap();
+        let (cards, bid) = s.try_split_once(" ")?;
+        let cards: Vec<Card> = cards.try_parse_chars().try_collect()?;
+        let bid = bid.try_parse()?;
         let hand_type = Self::get_type(&cards);
         Ok(Self {
             cards,
@@ -60,7 +63,7 @@ impl Ord for Hand {
                 .zip(other.cards.iter())
                 .map(|(card, other_card)| card.cmp(other_card))
                 .find(|ord| ord != &Ordering::Equal)
-                .unwrap(),
+                .unwrap_or(Ordering::Equal),
             x => x,
         }
     }
@@ -123,7 +126,7 @@ impl Hand {
 }
 
 // Confirm this derivation works like how I want it to
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
 enum HandType {
     HighCard,
     OnePair,
@@ -134,22 +137,24 @@ enum HandType {
     FiveOfaKind,
 }
 
-#[derive(PartialEq, PartialOrd, Eq, Ord, Debug)]
+#[derive(PartialEq, PartialOrd, Eq, Ord, Debug, Clone)]
 struct Card {
     strength: usize,
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         let strength = match value {
             'A' => 14,
             'K' => 13,
             'Q' => 12,
             'J' => 11,
             'T' => 10,
-            x => x.to_digit(10).unwrap() as usize,
+            x @ '2'..='9' => x.to_digit(10).unwrap() as usize,
+            x => return Err(ParseError::new(format!("Invalid card: {x}"))),
         };
-        Self { strength }
+        Ok(Self { strength })
     }
 }
 
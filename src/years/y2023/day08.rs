@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::prelude::*;
+
+pub struct Day08;
+impl Day for Day08 {
+    type Input<'a> = Instructions<'a>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, instruction: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        if !instruction.nodes.contains_key("AAA") {
+            return Err(SolveError::NoAnswer(
+                "there is no node AAA to start from".into(),
+            ));
+        }
+        Ok(instruction
+            .clone_with_starting_node("AAA")
+            .find_position(|node| node == &"ZZZ")
+            .map(|(index, _)| index + 1)
+            .unwrap())
+    }
+
+    fn star2(&self, instruction: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(instruction
+            .nodes
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|node| {
+                instruction
+                    .clone_with_starting_node(node)
+                    .find_position(|node| node.ends_with('Z'))
+                    .map(|(index, _)| index + 1)
+                    .unwrap()
+            })
+            // 🙏 myscon
+            .fold(1, num_integer::lcm))
+    }
+}
+
+pub struct Instructions<'a> {
+    instructions: Vec<Direction>,
+    nodes: HashMap<&'a str, [&'a str; 2]>,
+    prev_node: &'a str,
+    current_index: usize,
+}
+
+impl<'a> Parse<'a> for Instructions<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let mut lines = s.lines();
+        let first_line = lines.next().unwrap_or_default();
+        let instructions: Vec<Direction> = first_line.try_parse_chars().try_collect()?;
+        if instructions.is_empty() {
+            return Err(ParseError::at(first_line, "no left/right instructions"));
+        }
+
+        // empty line
+        lines.next();
+
+        // AAA = (BBB, CCC)
+        let nodes: HashMap<_, _> = lines
+            .map(|line| {
+                let (key, neighbors) = line.try_split_once(" = ")?;
+                let (left, right) = neighbors
+                    .try_strip_prefix("(")?
+                    .try_strip_suffix(")")?
+                    .try_split_once(", ")?;
+                Ok::<_, ParseError>((key, [left, right]))
+            })
+            .try_collect()?;
+
+        for neighbor in nodes.values().flatten() {
+            if !nodes.contains_key(neighbor) {
+                return Err(ParseError::at(neighbor, format!("unknown node {neighbor}")));
+            }
+        }
+
+        Ok(Self {
+            instructions,
+            nodes,
+            prev_node: "AAA",
+            current_index: 0,
+        })
+    }
+}
+
+impl<'a> Instructions<'a> {
+    fn clone_with_starting_node(&self, starting_node: &'a str) -> Self {
+        Self {
+            instructions: self.instructions.clone(),
+            nodes: self.nodes.clone(),
+            prev_node: starting_node,
+            current_index: self.current_index,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    L = 0,
+    R = 1,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::L),
+            'R' => Ok(Self::R),
+            c => Err(ParseError::new(format!(
+                "Could not parse direction from {c}"
+            ))),
+        }
+    }
+}
+
+impl<'a> Iterator for Instructions<'a> {
+    type Item = &'a str;
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self
+            .instructions
+            .get(self.current_index % self.instructions.len())
+            .unwrap();
+        self.current_index += 1;
+
+        let next_node = self.nodes.get(self.prev_node).unwrap()[*dir as usize];
+        self.prev_node = next_node;
+        Some(next_node)
+    }
+}
@@ -2,32 +2,31 @@ use crate::prelude::*;
 
 pub struct Day09;
 impl Day for Day09 {
-    fn star1(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .map(|h: History| find_next(h.values.into_iter()))
-            .sum::<isize>()
-            .to_string()
+    type Input<'a> = Vec<History>;
+    type Answer = isize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .iter()
+            .map(|h| find_next(h.values.iter().copied()))
+            .sum())
     }
 
-    fn star2(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .map(|h: History| find_prev(h.values))
-            .sum::<isize>()
-            .to_string()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.iter().map(|h| find_prev(h.values.clone())).sum())
     }
 }
 
-struct History {
+pub struct History {
     values: Vec<isize>,
 }
 
 impl FromStr for History {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(Self {
-            values: s.split_ascii_whitespace().parse_each().collect(),
+            values: s.split_ascii_whitespace().try_parse_each().try_collect()?,
         })
     }
 }
@@ -0,0 +1,283 @@
+use crate::{
+    prelude::*,
+    visualize::Frames,
+};
+
+use colored::Colorize;
+
+pub struct Day10;
+impl Day for Day10 {
+    type Input<'a> = PipeMaze;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.loop_length() / 2)
+    }
+
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.clone().set_entire_loop().fill_in_inside().num_found)
+    }
+
+    /// The loop, then everything inside it for star 2
+    fn visualize(
+        &self,
+        input: &Self::Input<'_>,
+        is_second_star: bool,
+        frames: &mut Frames,
+    ) -> Result<(), SolveError> {
+        let mut maze = input.clone();
+        frames.frame(maze.set_entire_loop().picture());
+        if is_second_star {
+            frames.frame(maze.fill_in_inside().picture());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct PipeMaze {
+    data: Grid<char>,
+    loop_pipes: Grid<bool>,
+    found_inside: Grid<bool>,
+    num_found: usize,
+    /// which way the pipe hidden under the `S` goes
+    start: [Direction; 2],
+}
+
+impl Parse<'_> for PipeMaze {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut starts = Vec::new();
+        for (i, c) in s.char_indices() {
+            match c {
+                '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | '\n' => (),
+                'S' => starts.push(&s[i..i + 1]),
+                _ => {
+                    return Err(ParseError::at(
+                        &s[i..i + c.len_utf8()],
+                        format!("Received invalid maze char {c}"),
+                    ))
+                }
+            }
+        }
+        let Ok(start_fragment) = starts.iter().exactly_one() else {
+            return Err(ParseError::new(format!(
+                "expected exactly one start but found {}",
+                starts.len()
+            )));
+        };
+
+        let data: Grid<char> = s.parse()?;
+        let bools = data.map(|_| false);
+        let start = start_directions(&data).ok_or_else(|| {
+            ParseError::at(
+                start_fragment,
+                "expected exactly two pipes to connect to the start",
+            )
+        })?;
+        Ok(Self {
+            loop_pipes: bools.clone(),
+            found_inside: bools,
+            data,
+            num_found: 0,
+            start,
+        })
+    }
+}
+
+impl PipeMaze {
+    fn start_pos(&self) -> Index {
+        self.data.indices().find(|i| self.data[*i] == 'S').unwrap()
+    }
+
+    fn loop_iter(&self) -> LoopIter {
+        LoopIter {
+            maze: self.clone(),
+            prev_dir: self.start[0],
+            prev_index: self.start_pos(),
+            finished: false,
+        }
+    }
+
+    fn loop_length(&self) -> usize {
+        self.loop_iter().count()
+    }
+
+    /// The map with the type of pipes colored in
+    fn picture(&self) -> String {
+        let mut picture = String::new();
+        for (i, row) in self.data.rows().enumerate() {
+            for (j, c) in row.iter().enumerate() {
+                let square = if self.found((i, j)) {
+                    "I".bright_red()
+                } else if c == &'S' {
+                    "S".bright_green()
+                } else if self.is_in_loop((i, j)) {
+                    c.to_string().yellow()
+                } else {
+                    c.to_string().normal()
+                };
+                picture += &square.to_string();
+            }
+            picture.push('\n');
+        }
+        picture
+    }
+
+    fn set_entire_loop(&mut self) -> &mut Self {
+        self.loop_iter().for_each(|(_, index)| self.set_loop(index));
+        self
+    }
+
+    fn fill_in_inside(&mut self) -> &mut Self {
+        let inside_of: fn(&Direction) -> Direction = match self.is_clockwise() {
+            true => Direction::right_dir,
+            false => Direction::left_dir,
+        };
+        let maze = &*self;
+        let next_to_loop = maze
+            .loop_iter()
+            .flat_map(|(came_from, index)| {
+                let next_dir = maze
+                    .directions_at(index)
+                    .into_iter()
+                    .find(|dir| *dir != came_from)
+                    .unwrap();
+                // the inside never goes off the edge but the outside can
+                [inside_of(&came_from.invert()), inside_of(&next_dir)]
+                    .into_iter()
+                    .filter_map(move |inside_dir| maze.data.step(index, inside_dir))
+            })
+            .collect_vec();
+        self.found_inside = self
+            .data
+            .flood_fill(next_to_loop, |index, _| !maze.is_in_loop(index));
+        self.num_found = self.found_inside.iter().filter(|found| **found).count();
+        self
+    }
+
+    /// Walking clockwise means the inside of the loop is always on our right.
+    /// Shoelace formula comes out negative for clockwise since rows count downwards
+    fn is_clockwise(&self) -> bool {
+        let corners = self.loop_iter().map(|(_, index)| index).collect_vec();
+        let twice_area: isize = corners
+            .iter()
+            .circular_tuple_windows()
+            .map(|(a, b)| a.0 as isize * b.1 as isize - b.0 as isize * a.1 as isize)
+            .sum();
+        twice_area < 0
+    }
+
+    /// type signature is kinda horrible
+    fn get_next(&self, prev_dir: Direction, index: Index) -> (Direction, Index) {
+        let next_dir = self
+            .directions_at(index)
+            .into_iter()
+            .find(|dir| *dir != prev_dir)
+            .unwrap();
+        let next_index = self
+            .data
+            .step(index, next_dir)
+            .expect("the loop stays inside the maze");
+        (next_dir.invert(), next_index)
+    }
+
+    fn directions_at(&self, index: Index) -> [Direction; 2] {
+        match self.char_at(index) {
+            'S' => self.start,
+            c => directions(c),
+        }
+    }
+
+    fn char_at(&self, index: Index) -> char {
+        self.data[index]
+    }
+
+    fn is_in_loop(&self, index: Index) -> bool {
+        self.loop_pipes[index]
+    }
+
+    fn set_loop(&mut self, index: Index) {
+        *self.loop_pipes.get_mut(index).unwrap() = true;
+    }
+
+    fn found(&self, index: Index) -> bool {
+        self.found_inside[index]
+    }
+}
+
+struct LoopIter {
+    maze: PipeMaze,
+    prev_dir: Direction,
+    prev_index: Index,
+    finished: bool,
+}
+
+impl Iterator for LoopIter {
+    type Item = (Direction, Index);
+    /// Item is direction we arrived from and the index we are at
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let (dir, index) = self.maze.get_next(self.prev_dir, self.prev_index);
+        if self.maze.char_at(index) == 'S' {
+            self.finished = true;
+        }
+        self.prev_dir = dir;
+        self.prev_index = index;
+        Some((self.prev_dir, self.prev_index))
+    }
+}
+
+fn directions(c: char) -> [Direction; 2] {
+    match c {
+        '.' => panic!("We somehow got to the ground in the maze... whoops"),
+        _ => connections(c).unwrap_or_else(|| panic!("Received invalid maze char {c}")),
+    }
+}
+
+fn connections(c: char) -> Option<[Direction; 2]> {
+    use Direction::*;
+    match c {
+        '|' => Some([North, South]),
+        '-' => Some([East, West]),
+        'L' => Some([North, East]),
+        'J' => Some([West, North]),
+        '7' => Some([West, South]),
+        'F' => Some([East, South]),
+        _ => None,
+    }
+}
+
+/// The start hides whichever pipe connects up to exactly two of its neighbors
+fn start_directions(data: &Grid<char>) -> Option<[Direction; 2]> {
+    let start = data.indices().find(|i| data[*i] == 'S')?;
+    Direction::ALL
+        .into_iter()
+        .filter(|dir| {
+            data.step(start, *dir)
+                .and_then(|i| connections(data[i]))
+                .is_some_and(|dirs| dirs.contains(&dir.invert()))
+        })
+        .collect_tuple()
+        .map(|(a, b)| [a, b])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Filling this in used to recurse once per square inside and overflow the stack
+    #[test]
+    fn fills_a_huge_loop() {
+        let size = 700;
+        let edge = "-".repeat(size - 2);
+        let middle = format!("|{}|\n", ".".repeat(size - 2)).repeat(size - 2);
+        let maze = format!("S{edge}7\n{middle}L{edge}J\n");
+
+        let maze = Day10.parse(&maze).unwrap();
+        assert_eq!(Day10.star1(&maze).unwrap(), 2 * (size - 1));
+        assert_eq!(Day10.star2(&maze).unwrap(), (size - 2) * (size - 2));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    prelude::*,
+    visualize::Frames,
+};
+
+pub struct Day11;
+impl Day for Day11 {
+    type Input<'a> = Galaxy;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .clone()
+            .expand(2)
+            .stars()
+            .tuple_combinations()
+            .map(|(i1, i2)| dist(i1, i2))
+            .sum::<usize>())
+    }
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        let e2 = input
+            .clone()
+            .expand(2)
+            .stars()
+            .tuple_combinations()
+            .map(|(i1, i2)| dist(i1, i2))
+            .sum::<usize>();
+
+        let e3 = input
+            .clone()
+            .expand(3)
+            .stars()
+            .tuple_combinations()
+            .map(|(i1, i2)| dist(i1, i2))
+            .sum::<usize>();
+
+        Ok((1_000_000 - 2) * (e3 - e2) + e2)
+    }
+
+    /// The galaxy before and after it expands. Star 2 shows the expansion it
+    /// extrapolates from rather than a million columns
+    fn visualize(
+        &self,
+        input: &Self::Input<'_>,
+        is_second_star: bool,
+        frames: &mut Frames,
+    ) -> Result<(), SolveError> {
+        frames.frame(&input.data);
+        frames.frame(&input.clone().expand(2).data);
+        if is_second_star {
+            frames.frame(&input.clone().expand(3).data);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Galaxy {
+    data: Grid<char>,
+}
+
+impl Parse<'_> for Galaxy {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self { data: s.parse()? })
+    }
+}
+
+impl Galaxy {
+    fn expand(mut self, size: usize) -> Self {
+        // find cols to expand
+        let cols_to_expand = self
+            .data
+            .cols()
+            .map(|mut col| col.all(|c| *c == '.').then_some('+'))
+            .collect_vec();
+
+        // expand cols
+        self.data = Grid::try_from_rows(self.data.rows().map(|row| {
+            row.iter()
+                .map(|c| Some(*c))
+                .interleave(cols_to_expand.iter().cloned())
+                .flatten()
+                .flat_map(|c| {
+                    if c == '+' {
+                        vec!['+'; size - 1]
+                    } else {
+                        vec![c]
+                    }
+                })
+                .collect()
+        }))
+        .expect("every row gets the same columns");
+
+        //expand rows
+        self.data = Grid::try_from_rows(self.data.into_iter().flat_map(|row| {
+            if row.iter().all(|c| *c != '#') {
+                vec![row.into_iter().map(|_| '.').collect_vec(); size]
+            } else {
+                vec![row]
+            }
+        }))
+        .expect("rows are only ever repeated");
+
+        self
+    }
+
+    fn stars(&self) -> impl '_ + Iterator<Item = Index> + Clone {
+        self.data.rows().enumerate().flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .flat_map(move |(j, c)| (*c == '#').then_some((i, j)))
+        })
+    }
+}
+
+fn dist(i1: Index, i2: Index) -> usize {
+    i1.0.abs_diff(i2.0) + i1.1.abs_diff(i2.1)
+}
@@ -3,17 +3,20 @@ use itertools::EitherOrBoth;
 
 pub struct Day12;
 impl Day for Day12 {
-    fn star1(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .map(|engine: Engine| {
+    type Input<'a> = Vec<Engine>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .iter()
+            .map(|engine| {
                 permutations(engine.parts.clone().into_iter())
                     .iter()
                     .filter(|parts| engine.is_valid(parts))
                     .count()
             })
-            .sum::<usize>()
-            .to_string()
+            .sum())
         // let engine = "?###???????? 3,2,1".parse::<Engine>().unwrap();
         // print_parts(&engine.parts);
         // println!();
@@ -26,18 +29,17 @@ impl Day for Day12 {
         // "HI".into()
     }
 
-    fn star2(&self, input: String) -> String {
-        input
-            .parsed_lines()
-            .map(|engine: Engine| engine.expand())
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .iter()
+            .map(|engine| engine.clone().expand())
             .map(|engine| {
                 permutations(engine.parts.clone().into_iter())
                     .iter()
                     .filter(|parts| engine.is_valid(parts))
                     .count()
             })
-            .sum::<usize>()
-            .to_string()
+            .sum())
     }
 }
 
@@ -46,17 +48,18 @@ impl Day for Day12 {
 ?#?#?#????????. 8,1
 */
 
-struct Engine {
+#[derive(Clone)]
+pub struct Engine {
     parts: Vec<Part>,
     broken_parts: Vec<usize>,
 }
 
 impl FromStr for Engine {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (parts_str, broken_parts_str) = s.split_once(' ').unwrap();
-        let parts = parts_str.chars().map(Part::from).collect();
-        let broken_parts = broken_parts_str.split(',').parse_each().collect();
+        let (parts_str, broken_parts_str) = s.try_split_once(" ")?;
+        let parts = parts_str.try_parse_chars().try_collect()?;
+        let broken_parts = broken_parts_str.split(',').try_parse_each().try_collect()?;
         Ok(Self {
             parts,
             broken_parts,
@@ -77,13 +80,11 @@ impl Engine {
     }
 
     fn expand(self) -> Self {
-        let parts = std::iter::repeat(self.parts)
-            .take(5)
-            .interleave(std::iter::repeat(vec![Part::Mystery]).take(4))
+        let parts = std::iter::repeat_n(self.parts, 5)
+            .interleave(std::iter::repeat_n(vec![Part::Mystery], 4))
             .flatten()
             .collect();
-        let broken_parts = std::iter::repeat(self.broken_parts)
-            .take(5)
+        let broken_parts = std::iter::repeat_n(self.broken_parts, 5)
             .flatten()
             .collect();
         Self {
@@ -138,13 +139,16 @@ enum Part {
     Mystery,
 }
 
-impl From<char> for Part {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Part {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '#' => Self::Broken,
-            '?' => Self::Mystery,
-            '.' => Self::Fixed,
-            c => panic!("Failed to parse from part from char: {c}"),
+            '#' => Ok(Self::Broken),
+            '?' => Ok(Self::Mystery),
+            '.' => Ok(Self::Fixed),
+            c => Err(ParseError::new(format!(
+                "Failed to parse from part from char: {c}"
+            ))),
         }
     }
 }
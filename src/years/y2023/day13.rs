@@ -0,0 +1,99 @@
+use crate::prelude::*;
+
+pub struct Day13;
+impl Day for Day13 {
+    type Input<'a> = Mirrors;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        input
+            .0
+            .iter()
+            .map(|m| m.find_reflection().map(|r| r.score()))
+            .sum()
+    }
+
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        input
+            .0
+            .iter()
+            .map(|m| m.find_reflection_smudge().map(|r| r.score()))
+            .sum()
+    }
+}
+
+pub struct Mirrors(Vec<Mirror>);
+
+impl Parse<'_> for Mirrors {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self(s.split("\n\n").try_parse_each().try_collect()?))
+    }
+}
+
+struct Mirror {
+    grid: Grid<char>,
+}
+
+impl FromStr for Mirror {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.parse()?;
+
+        Ok(Self { grid })
+    }
+}
+
+impl Mirror {
+    fn find_reflection(&self) -> Result<Reflection, SolveError> {
+        self.find_reflection_with(0)
+    }
+
+    fn find_reflection_smudge(&self) -> Result<Reflection, SolveError> {
+        self.find_reflection_with(1)
+    }
+
+    /// Looks for rows first, then columns as the rows of the transposed grid
+    fn find_reflection_with(&self, smudges: usize) -> Result<Reflection, SolveError> {
+        reflected_rows(&self.grid, smudges)
+            .map(Reflection::Horizontal)
+            .or_else(|| {
+                reflected_rows(&self.grid.view().transpose(), smudges).map(Reflection::Vertical)
+            })
+            .or_no_answer("Did not find horizontal or vertical reflection")
+    }
+}
+
+/// How many rows are above the line `grid` reflects across, where exactly
+/// `smudges` squares don't match their reflection
+fn reflected_rows(grid: &impl ReadGrid<char>, smudges: usize) -> Option<usize> {
+    (1..grid.num_rows()).find(|&above| {
+        let going_up = (0..above).rev().map(|i| grid.row(i));
+        let going_down = (above..grid.num_rows()).map(|i| grid.row(i));
+        // stops looking as soon as there are too many to be this line
+        let differences = going_up.zip(going_down).try_fold(0, |total, (r1, r2)| {
+            let mut pairs = r1.zip(r2);
+            let total = match smudges - total {
+                // none to spare so the first difference is enough to rule it out
+                0 => total + pairs.any(|(i1, i2)| i1 != i2) as usize,
+                spare => total + pairs.filter(|(i1, i2)| i1 != i2).take(spare + 1).count(),
+            };
+            (total <= smudges).then_some(total)
+        });
+        differences == Some(smudges)
+    })
+}
+
+enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Reflection {
+    fn score(&self) -> usize {
+        match self {
+            Self::Vertical(x) => *x,
+            Self::Horizontal(x) => 100 * x,
+        }
+    }
+}
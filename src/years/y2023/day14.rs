@@ -0,0 +1,195 @@
+use crate::{
+    prelude::*,
+    visualize::Frames,
+};
+use std::collections::HashMap;
+
+pub struct Day14;
+impl Day for Day14 {
+    type Input<'a> = Grid<LensPiece>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(Lens::new(input.clone()).roll(Direction::North).load())
+    }
+
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        let mut lens = Lens::new(input.clone());
+
+        lens.cache();
+
+        while lens.cycle_num < 1_000_000_000 {
+            if let Some(start_of_cycle) = lens.cycle().cache() {
+                let cycle_len = lens.cycle_num - start_of_cycle;
+                let remaining_cycles = 1_000_000_000 - lens.cycle_num;
+                let remainder = remaining_cycles % cycle_len;
+                for _ in 0..remainder {
+                    lens.cycle();
+                }
+                break;
+            }
+        }
+
+        Ok(lens.load())
+    }
+
+    /// Every tilt until the cycles start repeating
+    fn visualize(
+        &self,
+        input: &Self::Input<'_>,
+        is_second_star: bool,
+        frames: &mut Frames,
+    ) -> Result<(), SolveError> {
+        let mut lens = Lens::new(input.clone());
+        frames.frame(format!("load {}\n{lens}", lens.load()));
+        if !is_second_star {
+            lens.roll(Direction::North);
+            frames.frame(format!("load {}\n{lens}", lens.load()));
+            return Ok(());
+        }
+
+        lens.cache();
+        loop {
+            lens.cycle_num += 1;
+            for dir in [
+                Direction::North,
+                Direction::West,
+                Direction::South,
+                Direction::East,
+            ] {
+                lens.roll(dir);
+                frames.frame(format!(
+                    "cycle {} {dir:?}, load {}\n{lens}",
+                    lens.cycle_num,
+                    lens.load()
+                ));
+            }
+            if let Some(start_of_cycle) = lens.cache() {
+                frames.frame(format!(
+                    "cycle {} is back where cycle {start_of_cycle} was\n{lens}",
+                    lens.cycle_num
+                ));
+                return Ok(());
+            }
+        }
+    }
+}
+
+struct Lens {
+    grid: Grid<LensPiece>,
+    cache: HashMap<Grid<LensPiece>, usize>,
+    cycle_num: usize,
+}
+
+impl Lens {
+    fn new(grid: Grid<LensPiece>) -> Self {
+        Self {
+            grid,
+            cache: HashMap::new(),
+            cycle_num: 0,
+        }
+    }
+
+    fn cycle(&mut self) -> &mut Self {
+        self.cycle_num += 1;
+        self.roll(Direction::North)
+            .roll(Direction::West)
+            .roll(Direction::South)
+            .roll(Direction::East)
+    }
+
+    /// Turns the grid so `dir` is to the left, rolls every row that way and
+    /// writes the rocks back where they came from
+    fn roll(&mut self, dir: Direction) -> &mut Self {
+        let view = self.grid.view();
+        let view = match dir {
+            Direction::North => view.transpose(),
+            Direction::East => view.flip_horizontal(),
+            Direction::South => view.transpose().flip_horizontal(),
+            Direction::West => view,
+        };
+
+        let rolled = Self::roll_on_axis(view.rows())
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.into_iter()
+                    .enumerate()
+                    .map(move |(j, piece)| (view.source((i, j)), piece))
+            })
+            .collect_vec();
+        for (i, piece) in rolled {
+            self.grid[i] = piece;
+        }
+
+        self
+    }
+
+    fn roll_on_axis<'a>(
+        axis: impl Iterator<Item = impl Iterator<Item = &'a LensPiece>>,
+    ) -> Vec<Vec<LensPiece>> {
+        axis.map(|ax| {
+            ax.group_by(|piece| matches!(piece, LensPiece::SquareRock))
+                .into_iter()
+                .flat_map(|(_, i)| i.sorted().copied())
+                .collect()
+        })
+        .collect()
+    }
+
+    fn load(&self) -> usize {
+        self.grid.rows().rev().enumerate().sum_by(|(i, row)| {
+            row.iter()
+                .filter(|piece| matches!(piece, LensPiece::RoundRock))
+                .count()
+                * (i + 1)
+        })
+    }
+
+    /// cache the current grid state and cycle num. If the grid appeared in the cache then return the cycle num for it
+    fn cache(&mut self) -> Option<usize> {
+        self.cache.insert(self.grid.clone(), self.cycle_num)
+    }
+}
+
+impl std::fmt::Display for Lens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum LensPiece {
+    RoundRock,
+    SquareRock,
+    Lens,
+}
+
+impl TryFrom<char> for LensPiece {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Self::SquareRock),
+            'O' => Ok(Self::RoundRock),
+            '.' => Ok(Self::Lens),
+            _ => Err(ParseError::new(format!("Invalid lens piece {value}"))),
+        }
+    }
+}
+
+impl From<LensPiece> for char {
+    fn from(value: LensPiece) -> Self {
+        match value {
+            LensPiece::Lens => '.',
+            LensPiece::RoundRock => 'O',
+            LensPiece::SquareRock => '#',
+        }
+    }
+}
+
+impl std::fmt::Display for LensPiece {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self))
+    }
+}
@@ -7,17 +7,20 @@ use std::num::Wrapping;
 
 pub struct Day15;
 impl Day for Day15 {
-    fn star1(&self, input: String) -> String {
-        input.split(',').map(hash).sum::<usize>().to_string()
+    type Input<'a> = &'a str;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.split(',').map(hash).sum())
     }
 
-    fn star2(&self, input: String) -> String {
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
         let mut map = HashMap::new();
-        input
-            .split(',')
-            .parse_each()
-            .for_each(|inst| map.do_instruction(inst));
-        map.focusing_power().to_string()
+        for inst in input.trim_end().split(',').try_parse_each() {
+            map.do_instruction(inst?);
+        }
+        Ok(map.focusing_power())
     }
 }
 
@@ -42,15 +45,13 @@ enum Instruction {
 }
 
 impl FromStr for Instruction {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split(['=', '-']);
-        let label = split.next().unwrap();
-        match split.next().and_then(|maybe_num| maybe_num.parse().ok()) {
-            None => Ok(Self::Remove(label.into())),
-            Some(focal_length) => Ok(Self::Insert(LabeledLens {
+        match s.split_once('=') {
+            None => Ok(Self::Remove(s.try_strip_suffix("-")?.into())),
+            Some((label, focal_length)) => Ok(Self::Insert(LabeledLens {
                 label: label.into(),
-                length: focal_length,
+                length: focal_length.try_parse()?,
             })),
         }
     }
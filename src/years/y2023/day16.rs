@@ -0,0 +1,221 @@
+use crate::{
+    prelude::*,
+    visualize::Frames,
+};
+
+pub struct Day16;
+impl Day for Day16 {
+    type Input<'a> = MirrorMaze;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input.num_energized((0, 0), Direction::West))
+    }
+
+    fn star2(&self, maze: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        maze.entrances()
+            .map(|(i, coming_from)| maze.num_energized(i, coming_from))
+            .max()
+            .or_no_answer("the maze is empty")
+    }
+
+    /// The beam spreading out one square at a time. Star 2 shows the best entrance
+    fn visualize(
+        &self,
+        maze: &Self::Input<'_>,
+        is_second_star: bool,
+        frames: &mut Frames,
+    ) -> Result<(), SolveError> {
+        let (i, coming_from) = match is_second_star {
+            false => ((0, 0), Direction::West),
+            true => maze
+                .entrances()
+                .max_by_key(|(i, coming_from)| maze.num_energized(*i, *coming_from))
+                .or_no_answer("the maze is empty")?,
+        };
+        maze.propagate(i, coming_from, frames);
+        Ok(())
+    }
+}
+
+pub struct MirrorMaze {
+    grid: Grid<Mirror>,
+}
+
+impl Parse<'_> for MirrorMaze {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        Ok(Self { grid: s.parse()? })
+    }
+}
+
+impl MirrorMaze {
+    /// Everywhere a beam can come in from, going round the edge
+    fn entrances(&self) -> impl Iterator<Item = (Index, Direction)> + '_ {
+        let (rows, cols) = match (self.grid.num_rows(), self.grid.num_cols()) {
+            // blank lines are rows with no squares to come in through
+            (0, _) | (_, 0) => (0, 0),
+            shape => shape,
+        };
+        std::iter::empty()
+            // from top
+            .chain((0..cols).map(|j| ((0, j), Direction::North)))
+            // from bottom
+            .chain((0..cols).map(move |j| ((rows - 1, j), Direction::South)))
+            // from left
+            .chain((0..rows).map(|i| ((i, 0), Direction::West)))
+            // from right
+            .chain((0..rows).map(move |i| ((i, cols - 1), Direction::East)))
+    }
+
+    fn num_energized(&self, i: Index, coming_from: Direction) -> usize {
+        self.traverse(i, coming_from)
+            .iter()
+            .filter(|square| !square.is_empty())
+            .count()
+    }
+
+    /// For each grid square the directions beams came into it from
+    fn traverse(&self, i: Index, coming_from: Direction) -> Grid<Vec<Direction>> {
+        self.grid
+            .walk([(i, coming_from)], |i, coming_from, mirror| {
+                mirror
+                    .next_dir(*coming_from)
+                    .into_iter()
+                    .filter_map(move |next_dir| {
+                        Some((self.grid.step(i, next_dir)?, next_dir.invert()))
+                    })
+            })
+    }
+
+    /// Same as [`Self::traverse`] but breadth first so every step of the beam is a frame
+    fn propagate(&self, i: Index, coming_from: Direction, frames: &mut Frames) {
+        // for each grid square the directions we have gone to on the square
+        let mut traversed = self.grid.map(|_| Vec::new());
+        let mut beams = vec![(i, coming_from)];
+        while !beams.is_empty() {
+            let mut next_beams = Vec::new();
+            for (i, coming_from) in beams {
+                let Some(mirror) = self.grid.get(i) else {
+                    continue;
+                };
+                for next_dir in mirror.next_dir(coming_from) {
+                    if traversed[i].contains(&next_dir) {
+                        continue;
+                    }
+                    traversed.get_mut(i).unwrap().push(next_dir);
+                    if let Some(next_index) = self.grid.step(i, next_dir) {
+                        next_beams.push((next_index, next_dir.invert()));
+                    }
+                }
+            }
+            beams = next_beams;
+            let energized = traversed.iter().filter(|square| !square.is_empty());
+            frames.frame(format!(
+                "{} energized\n{}",
+                energized.count(),
+                self.energized(&traversed)
+            ));
+        }
+    }
+
+    /// The mirrors with the energized empty squares as `#`
+    fn energized(&self, traversed: &Grid<Vec<Direction>>) -> Grid<char> {
+        self.grid.map_indexed(|i, mirror| match mirror {
+            Mirror::None if !traversed[i].is_empty() => '#',
+            mirror => char::from(mirror),
+        })
+    }
+}
+
+enum Mirror {
+    SplitToVertical,
+    SplitToHorizontal,
+    LeftToUp,
+    LeftToDown,
+    None,
+}
+
+impl Mirror {
+    fn next_dir(&self, coming_from: Direction) -> Vec<Direction> {
+        match (self, coming_from) {
+            (Self::SplitToHorizontal, Direction::East | Direction::West) => {
+                vec![coming_from.invert()]
+            }
+            (Self::SplitToHorizontal, Direction::North | Direction::South) => {
+                vec![Direction::East, Direction::West]
+            }
+            (Self::SplitToVertical, Direction::North | Direction::South) => {
+                vec![coming_from.invert()]
+            }
+            (Self::SplitToVertical, Direction::East | Direction::West) => {
+                vec![Direction::North, Direction::South]
+            }
+            (Self::None, _) => vec![coming_from.invert()],
+            // '\'
+            (Self::LeftToDown, Direction::North) => vec![Direction::East],
+            (Self::LeftToDown, Direction::East) => vec![Direction::North],
+            (Self::LeftToDown, Direction::South) => vec![Direction::West],
+            (Self::LeftToDown, Direction::West) => vec![Direction::South],
+            // '/'
+            (Self::LeftToUp, Direction::North) => vec![Direction::West],
+            (Self::LeftToUp, Direction::East) => vec![Direction::South],
+            (Self::LeftToUp, Direction::South) => vec![Direction::East],
+            (Self::LeftToUp, Direction::West) => vec![Direction::North],
+        }
+    }
+}
+
+impl From<&Mirror> for char {
+    fn from(mirror: &Mirror) -> Self {
+        match mirror {
+            Mirror::None => '.',
+            Mirror::LeftToDown => '\\',
+            Mirror::LeftToUp => '/',
+            Mirror::SplitToHorizontal => '-',
+            Mirror::SplitToVertical => '|',
+        }
+    }
+}
+
+impl TryFrom<char> for Mirror {
+    type Error = ParseError;
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Mirror::None),
+            '\\' => Ok(Mirror::LeftToDown),
+            '/' => Ok(Mirror::LeftToUp),
+            '-' => Ok(Mirror::SplitToHorizontal),
+            '|' => Ok(Mirror::SplitToVertical),
+            _ => Err(ParseError::new(format!("Recieve invalid Mirror char {c}"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A beam that snakes through every square, which used to recurse once per square
+    #[test]
+    fn follows_a_huge_beam() {
+        let size = 500;
+        let middle = ".".repeat(size - 2);
+        let maze = (0..size / 2)
+            .map(|pair| {
+                let start = if pair == 0 { '.' } else { '\\' };
+                format!("{start}{middle}\\\n/{middle}/\n")
+            })
+            .collect::<String>();
+
+        let maze = Day16.parse(&maze).unwrap();
+        assert_eq!(Day16.star1(&maze).unwrap(), size * size);
+    }
+
+    #[test]
+    fn blank_maze_has_no_entrances() {
+        let maze = Day16.parse("\n\n").unwrap();
+        assert_eq!(Day16.star1(&maze).unwrap(), 0);
+        assert!(matches!(Day16.star2(&maze), Err(SolveError::NoAnswer(_))));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    prelude::*,
+    search,
+    visualize::Frames,
+};
+
+pub struct Day17;
+impl Day for Day17 {
+    type Input<'a> = City;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        input
+            .find_path(City::get_next_dist, 0, |_, _| ())
+            .goal_distance()
+            .or_no_answer("could not reach the factory")
+    }
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        input
+            .find_path(City::get_next_dist_ultra, 4, |_, _| ())
+            .goal_distance()
+            .or_no_answer("could not reach the factory")
+    }
+
+    /// The search frontier, a frame each time the heat loss goes up, then the path it found
+    fn visualize(
+        &self,
+        input: &Self::Input<'_>,
+        is_second_star: bool,
+        frames: &mut Frames,
+    ) -> Result<(), SolveError> {
+        let mut reached = input.streets.map(|_| false);
+        let mut heat_loss = 0;
+        let mut draw = |reached: &Grid<bool>, heat_loss: usize| {
+            frames.frame(format!("heat loss {heat_loss}\n{}", input.picture(reached)))
+        };
+        let on_visit = |crucible: &Crucible, so_far: usize| {
+            if so_far > heat_loss {
+                draw(&reached, heat_loss);
+                heat_loss = so_far;
+            }
+            *reached.get_mut(crucible.pos).unwrap() = true;
+        };
+        let found = match is_second_star {
+            false => input.find_path(City::get_next_dist, 0, on_visit),
+            true => input.find_path(City::get_next_dist_ultra, 4, on_visit),
+        };
+        let (Some(least_heat_loss), Some(path)) = (found.goal_distance(), found.path()) else {
+            return Err(SolveError::NoAnswer("could not reach the factory".into()));
+        };
+
+        let mut picture = input.picture(&reached);
+        for crucible in path {
+            *picture.get_mut(crucible.pos).unwrap() = '@';
+        }
+        frames.frame(format!("heat loss {least_heat_loss}\n{picture}"));
+        Ok(())
+    }
+}
+
+pub struct City {
+    streets: Grid<u8>,
+}
+
+/// Everything that decides where the crucible can go next
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Crucible {
+    pos: Index,
+    arrived_facing: Direction,
+    longest_straight_path: u8,
+}
+
+impl City {
+    /// Finds the goal unless there is no way to the bottom right.
+    ///
+    /// The crucible has to have gone straight for at least `min_to_stop` blocks to stop at the end.
+    /// `on_visit` sees every crucible once along with the heat lost getting there
+    fn find_path(
+        &self,
+        next_pass_fn: impl Fn(Direction, &Crucible) -> Option<u8>,
+        min_to_stop: u8,
+        mut on_visit: impl FnMut(&Crucible, usize),
+    ) -> search::Search<Crucible> {
+        let start = Crucible {
+            pos: (0, 0),
+            // this could be South or East
+            arrived_facing: Direction::South,
+            longest_straight_path: 0,
+        };
+        let final_pos = (self.streets.num_rows() - 1, self.streets.num_cols() - 1);
+        let next_pass_fn = &next_pass_fn;
+
+        search::dijkstra(
+            start,
+            |crucible| {
+                let crucible = *crucible;
+                self.next_steps(crucible.pos, crucible.arrived_facing)
+                    .filter_map(move |(dir, pos)| {
+                        let next = Crucible {
+                            pos,
+                            arrived_facing: dir,
+                            longest_straight_path: next_pass_fn(dir, &crucible)?,
+                        };
+                        Some((next, self.streets[pos] as usize))
+                    })
+            },
+            |crucible, heat_loss| {
+                on_visit(crucible, heat_loss);
+                crucible.pos == final_pos && crucible.longest_straight_path >= min_to_stop
+            },
+        )
+    }
+
+    /// Returns None if its not a valid dir
+    fn get_next_dist(going_to: Direction, current_node: &Crucible) -> Option<u8> {
+        match (
+            going_to == current_node.arrived_facing,
+            current_node.longest_straight_path,
+        ) {
+            (true, 0..=2) => Some(current_node.longest_straight_path + 1),
+            (false, _) => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Returns None if its not a valid dir
+    fn get_next_dist_ultra(going_to: Direction, current_node: &Crucible) -> Option<u8> {
+        match (
+            going_to == current_node.arrived_facing,
+            current_node.longest_straight_path,
+        ) {
+            (true, 0..=9) => Some(current_node.longest_straight_path + 1),
+            (false, 4..) => Some(1),
+            // edge case for the first node
+            (false, 0) => Some(1),
+            _ => None,
+        }
+    }
+
+    /// The blocks with every one the search has reached so far as `#`
+    fn picture(&self, reached: &Grid<bool>) -> Grid<char> {
+        self.streets.map_indexed(|i, heat_loss| match reached[i] {
+            true => '#',
+            false => char::from(b'0' + heat_loss),
+        })
+    }
+
+    /// Straight on, right and left along with where each one ends up, if it's still in the city
+    fn next_steps(
+        &self,
+        pos: Index,
+        arrived_facing: Direction,
+    ) -> impl Iterator<Item = (Direction, Index)> + '_ {
+        [
+            arrived_facing,
+            arrived_facing.right_dir(),
+            arrived_facing.left_dir(),
+        ]
+        .into_iter()
+        .filter_map(move |dir| Some((dir, self.streets.step(pos, dir)?)))
+    }
+}
+
+struct Street(u8);
+
+impl TryFrom<char> for Street {
+    type Error = ParseError;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        value
+            .to_digit(10)
+            .map(|heat_loss| Self(heat_loss as u8))
+            .ok_or_else(|| ParseError::new(format!("Expected a digit but got {value}")))
+    }
+}
+
+impl Parse<'_> for City {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let streets = s.parse::<Grid<Street>>()?.map(|street| street.0);
+        if streets.num_rows() == 0 || streets.num_cols() == 0 {
+            return Err(ParseError::new("The city is empty"));
+        }
+        Ok(Self { streets })
+    }
+}
@@ -3,15 +3,17 @@ use std::collections::VecDeque;
 
 pub struct Day18;
 impl Day for Day18 {
-    fn star1(&self, input: String) -> String {
-        Trenches::new(input.parsed_lines()).area().to_string()
+    type Input<'a> = Vec<DigInstruction>;
+    type Answer = i64;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(Trenches::new(input.iter().cloned()).area())
     }
 
-    fn star2(&self, input: String) -> String {
-        let insts = input
-            .parsed_lines()
-            .map(|inst: DigInstruction| inst.into_color_mode());
-        Trenches::new(insts).area().to_string()
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        let insts = input.iter().cloned().map(DigInstruction::into_color_mode);
+        Ok(Trenches::new(insts).area())
     }
 }
 
@@ -35,8 +37,7 @@ impl Iterator for Trenches {
     type Item = SIndex;
     fn next(&mut self) -> Option<Self::Item> {
         let next_pos = self.insts.pop_front().map(|inst| {
-            std::iter::repeat(inst.dir)
-                .take(inst.amount)
+            std::iter::repeat_n(inst.dir, inst.amount)
                 .fold(self.current_pos, |loc, dir| dir.sgo(loc))
         })?;
 
@@ -55,7 +56,10 @@ impl Trenches {
 
     fn area(self) -> i64 {
         let mut iter = self.peekable();
-        let first = *iter.peek().unwrap();
+        let Some(&first) = iter.peek() else {
+            // nothing was dug
+            return 0;
+        };
         let (area_2, perim) = iter
             .chain(std::iter::once(first))
             .tuple_windows()
@@ -66,7 +70,7 @@ impl Trenches {
 }
 
 #[derive(Debug, Clone)]
-struct DigInstruction {
+pub struct DigInstruction {
     dir: Direction,
     amount: usize,
     color_dist: usize,
@@ -84,20 +88,34 @@ impl DigInstruction {
 }
 
 impl FromStr for DigInstruction {
-    type Err = Infallible;
+    type Err = ParseError;
     /// L 4 (#327cb0)
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split_ascii_whitespace();
-        let dir = split.next().unwrap().chars().next().unwrap().into();
-        let amount = split.next().unwrap().parse().unwrap();
-        let color = &split.next().unwrap()[2..8];
-        let color_dist = usize::from_str_radix(&color[0..5], 16).unwrap();
+        let Some((dir, amount, color)) = s.split_ascii_whitespace().collect_tuple() else {
+            return Err(ParseError::at(s, "expected direction, amount and color"));
+        };
+        let dir = match dir.try_parse_chars().exactly_one() {
+            Ok(dir) => dir?,
+            Err(_) => return Err(ParseError::at(dir, format!("invalid direction: {dir}"))),
+        };
+        let amount = amount.try_parse()?;
+        let color = color.try_strip_prefix("(#")?.try_strip_suffix(")")?;
+        if color.len() != 6 || !color.is_ascii() {
+            return Err(ParseError::at(color, format!("invalid color: {color}")));
+        }
+        let color_dist = usize::from_str_radix(&color[0..5], 16)
+            .map_err(|e| ParseError::at(&color[0..5], e.to_string()))?;
         let color_dir = match &color[5..6] {
             "0" => Direction::West,
             "1" => Direction::South,
             "2" => Direction::East,
             "3" => Direction::North,
-            x => panic!("invalid color dist: {x}"),
+            x => {
+                return Err(ParseError::at(
+                    &color[5..6],
+                    format!("invalid color dist: {x}"),
+                ))
+            }
         };
         Ok(Self {
             dir,
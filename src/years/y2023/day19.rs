@@ -9,30 +9,58 @@ use std::{
 
 pub struct Day19;
 impl Day for Day19 {
-    fn star1(&self, input: String) -> String {
-        let (workflows, parts) = input.split_once("\n\n").unwrap();
-        let workflows = Workflow::parse_map(workflows);
-        parts
-            .parsed_lines::<Part>()
-            .filter(|part| check_part(&workflows, part))
-            .sum_by(Part::score)
-            .to_string()
+    type Input<'a> = System<'a>;
+    type Answer = usize;
+    const STARS: [bool; 2] = [true, true];
+
+    fn star1(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
+        Ok(input
+            .parts
+            .iter()
+            .filter(|part| check_part(&input.workflows, part))
+            .sum_by(Part::score))
     }
 
-    fn star2(&self, input: String) -> String {
-        let (workflows, _parts) = input.split_once("\n\n").unwrap();
-        let workflows = Workflow::parse_map(workflows);
+    fn star2(&self, input: &Self::Input<'_>) -> Result<Self::Answer, SolveError> {
         let part_range = PartRange {
             x: 1..4001,
             m: 1..4001,
             a: 1..4001,
             s: 1..4001,
         };
-        check_part_range(&workflows, &part_range)
+        Ok(check_part_range(&input.workflows, &part_range)
             .into_iter()
             .map(|r| debug!(r))
-            .sum_by(PartRange::combos)
-            .to_string()
+            .sum_by(PartRange::combos))
+    }
+}
+
+pub struct System<'a> {
+    workflows: HashMap<&'a str, Workflow<'a>>,
+    parts: Vec<Part>,
+}
+
+impl<'a> Parse<'a> for System<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let (workflows, parts) = s.try_split_once("\n\n")?;
+        let workflows = Workflow::parse_map(workflows)?;
+
+        // make sure every part has somewhere to go
+        if !workflows.contains_key("in") {
+            return Err(ParseError::new("no `in` workflow"));
+        }
+        for rule in workflows.values().flat_map(|w| &w.rules) {
+            if let Label::Workflow(next) = rule.label {
+                if !workflows.contains_key(next) {
+                    return Err(ParseError::at(next, format!("unknown workflow: {next}")));
+                }
+            }
+        }
+
+        Ok(Self {
+            workflows,
+            parts: parts.try_parsed_lines().try_collect()?,
+        })
     }
 }
 
@@ -54,7 +82,7 @@ impl Part {
         }
     }
 
-    fn score(self) -> usize {
+    fn score(&self) -> usize {
         self.x + self.m + self.a + self.s
     }
 }
@@ -95,23 +123,24 @@ impl PartRange {
 }
 
 impl FromStr for Part {
-    type Err = Infallible;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         // {x=787,m=2655,a=1222,s=2876}
-        let mut nums = s
-            .strip_prefix('{')
-            .unwrap()
-            .strip_suffix('}')
-            .unwrap()
-            .split(',')
-            .map(|type_assign| &type_assign[2..])
-            .parse_each::<usize>();
+        let mut ratings = s.try_strip_prefix("{")?.try_strip_suffix("}")?.split(',');
+        let mut rating = |typ: &str| {
+            ratings
+                .next()
+                .ok_or_else(|| ParseError::at(s, format!("missing {typ} rating")))?
+                .try_strip_prefix(typ)?
+                .try_strip_prefix("=")?
+                .try_parse()
+        };
 
         Ok(Self {
-            x: nums.next().unwrap(),
-            m: nums.next().unwrap(),
-            a: nums.next().unwrap(),
-            s: nums.next().unwrap(),
+            x: rating("x")?,
+            m: rating("m")?,
+            a: rating("a")?,
+            s: rating("s")?,
         })
     }
 }
@@ -121,23 +150,27 @@ struct Workflow<'a> {
 }
 
 impl<'a> Workflow<'a> {
-    fn parse_map(s: &'a str) -> HashMap<&'a str, Workflow<'a>> {
+    fn parse_map(s: &'a str) -> Result<HashMap<&'a str, Workflow<'a>>, ParseError> {
         s.lines()
             .map(|line| {
                 // qqz{s>2770:qs,m<1801:hdj,R}
-                let (label, rules) = line.split_once('{').unwrap();
-                let rules = rules
-                    .strip_suffix('}')
-                    .unwrap()
-                    .split(',')
-                    .map(Rule::from)
-                    .collect();
-                (label, Workflow { rules })
+                let (label, rules) = line.try_split_once("{")?;
+                let rules = rules.try_strip_suffix("}")?;
+                // the last rule is the fallback so check_part always finds a label
+                if rules.rsplit(',').next().unwrap_or_default().contains(':') {
+                    return Err(ParseError::at(
+                        rules,
+                        "workflow must end in a fallback rule",
+                    ));
+                }
+                let rules = rules.split(',').map(Rule::parse).try_collect()?;
+                Ok((label, Workflow { rules }))
             })
             .collect()
     }
 
     fn check_part(&'a self, part: &Part) -> Label<'a> {
+        // parsing made sure there is always a terminal rule at the end
         self.rules.iter().find_map(|rule| rule.check(part)).unwrap()
     }
 
@@ -163,7 +196,7 @@ fn check_part<'a>(map: &HashMap<&'a str, Workflow<'a>>, part: &Part) -> bool {
         match next_label {
             Label::Accept => return true,
             Label::Reject => return false,
-            Label::Label(next) => workflow = map.get(next).unwrap(),
+            Label::Workflow(next) => workflow = map.get(next).unwrap(),
         }
     }
 }
@@ -178,7 +211,7 @@ fn check_part_range<'a>(map: &HashMap<&'a str, Workflow<'a>>, parts: &PartRange)
             match label {
                 Label::Accept => pasing_parts.push(range),
                 Label::Reject => continue,
-                Label::Label(next) => parts_to_check.push_back((next, range)),
+                Label::Workflow(next) => parts_to_check.push_back((next, range)),
             }
         }
     }
@@ -202,12 +235,12 @@ impl<'a> Rule<'a> {
         }
     }
 
-    fn check(&self, part: &Part) -> Option<Label> {
+    fn check(&self, part: &Part) -> Option<Label<'a>> {
         (self.cmp)(&part.get(self.part_type), &self.other).then_some(self.label)
     }
 
     fn check_range(&self, part: &PartRange) -> CheckRange {
-        #[allow(clippy::fn_address_comparisons)]
+        #[allow(unpredictable_function_pointer_comparisons)]
         // clippy is probably right to deny this but lets try it anyway
         let offset = (self.cmp == usize::gt) as usize;
         let (lt, gt) = part.split_at(self.part_type, self.other + offset);
@@ -229,24 +262,32 @@ struct CheckRange {
     fails_rule: PartRange,
 }
 
-impl<'a> From<&'a str> for Rule<'a> {
-    fn from(s: &'a str) -> Self {
+impl<'a> Rule<'a> {
+    fn parse(s: &'a str) -> Result<Self, ParseError> {
         let Some((compare, label)) = s.split_once(':') else {
-            return Self::terminal(s);
+            return Ok(Self::terminal(s));
         };
-        let part_type = &compare[0..1];
-        let cmp = match &compare[1..2] {
+        let (part_type, cmp, other) = match compare.find(['<', '>']) {
+            Some(i) if matches!(&compare[..i], "x" | "m" | "a" | "s") => {
+                (&compare[..i], &compare[i..i + 1], &compare[i + 1..])
+            }
+            _ => {
+                return Err(ParseError::at(
+                    compare,
+                    format!("Invalid comparison: {compare}"),
+                ))
+            }
+        };
+        let cmp = match cmp {
             ">" => usize::gt,
-            "<" => usize::lt,
-            x => panic!("Invalid comarison operator: {x}"),
+            _ => usize::lt,
         };
-        let other = compare[2..].parse().unwrap();
-        Self {
+        Ok(Self {
             part_type,
             cmp,
             label: label.into(),
-            other,
-        }
+            other: other.try_parse()?,
+        })
     }
 }
 
@@ -254,7 +295,7 @@ impl<'a> From<&'a str> for Rule<'a> {
 enum Label<'a> {
     Accept,
     Reject,
-    Label(&'a str),
+    Workflow(&'a str),
 }
 
 impl<'a> From<&'a str> for Label<'a> {
@@ -262,7 +303,7 @@ impl<'a> From<&'a str> for Label<'a> {
         match s {
             "A" => Self::Accept,
             "R" => Self::Reject,
-            s => Self::Label(s),
+            s => Self::Workflow(s),
         }
     }
 }